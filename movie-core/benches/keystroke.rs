@@ -0,0 +1,47 @@
+//! Latency of one keystroke in the library search box against the full IMDb.txt
+//! catalog: parsing the query typed so far and scoring the catalog with it,
+//! once through the inverted index and once by scoring every movie directly.
+//!
+//!     cargo bench -p movie-core
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use movie_core::catalog::{self, IMDB_DATA};
+use movie_core::index::SearchIndex;
+use movie_core::model::Movie;
+use movie_core::query::Query;
+
+/// The first letters typed match the most words, so they are the slowest keystrokes.
+const TYPED: [&str; 5] = [
+    "t",
+    "to",
+    "tom hardy",
+    "zoe kravits",
+    "actor:mifune rating:>=8",
+];
+
+fn keystrokes(c: &mut Criterion) {
+    let movies: Vec<Movie> = catalog::parse(IMDB_DATA).map(Result::unwrap).collect();
+    let index = SearchIndex::new(movies.clone());
+
+    let mut group = c.benchmark_group("keystroke");
+    for typed in TYPED {
+        group.bench_with_input(BenchmarkId::new("index", typed), typed, |b, typed| {
+            b.iter(|| {
+                let query = Query::parse(typed).unwrap();
+                index.scores(&query)
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("linear", typed), typed, |b, typed| {
+            b.iter(|| {
+                let query = Query::parse(typed).unwrap();
+                movies.iter().map(|m| query.score(m)).collect::<Vec<_>>()
+            })
+        });
+    }
+    group.finish();
+
+    c.bench_function("build index", |b| b.iter(|| SearchIndex::new(movies.clone())));
+}
+
+criterion_group!(benches, keystrokes);
+criterion_main!(benches);
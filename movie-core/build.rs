@@ -0,0 +1,315 @@
+//! Checks data/awards.json and data/popular.json and turns them into the
+//! `AWARDS` table of src/award.rs and the `POPULAR` table of src/popular.rs,
+//! so a broken manifest fails the build instead of the page. Entries are also
+//! matched to the movies of data/IMDb.txt by name, and the ones without a match
+//! are flagged.
+
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::{env, fs, process};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer};
+
+const AWARDS: &str = "data/awards.json";
+const POPULAR: &str = "data/popular.json";
+const CATALOG: &str = "data/IMDb.txt";
+
+/// One entry of the award manifest. `about` and `won` name text files next to it.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AwardEntry {
+    title: String,
+    #[serde(default, deserialize_with = "present")]
+    movie: Option<Option<String>>,
+    about: String,
+    won: String,
+    trailer: String,
+    img: String,
+    #[serde(default)]
+    results: Vec<Ceremony>,
+}
+
+/// The categories a movie won or was nominated in at one ceremony.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Ceremony {
+    ceremony: String,
+    edition: Option<u16>,
+    year: u16,
+    #[serde(default)]
+    won: Vec<String>,
+    #[serde(default)]
+    nominated: Vec<String>,
+}
+
+/// One entry of the popular manifest. The last three fields name html files next to it.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PopularEntry {
+    title: String,
+    #[serde(default, deserialize_with = "present")]
+    movie: Option<Option<String>>,
+    genre: String,
+    runtime: u16,
+    description: String,
+    actors: String,
+    gross: String,
+}
+
+/// Tells `"movie": null`, an entry known not to be in the catalog, from a
+/// missing `movie`, which falls back to the title.
+fn present<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Option<String>>, D::Error> {
+    Option::deserialize(d).map(Some)
+}
+
+struct Build {
+    dir: PathBuf,
+    /// The name of every movie of the catalog.
+    catalog: Vec<String>,
+    errors: Vec<String>,
+}
+
+fn main() {
+    let dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    println!("cargo:rerun-if-changed={}", CATALOG);
+    let catalog = fs::read_to_string(dir.join(CATALOG))
+        .unwrap_or_else(|e| fail(&[format!("{}: can't be read: {}", CATALOG, e)]));
+    let mut build = Build {
+        catalog: catalog.lines().filter_map(movie_name).collect(),
+        dir,
+        errors: Vec::new(),
+    };
+    let awards = build.awards();
+    let popular = build.popular();
+    if !build.errors.is_empty() {
+        fail(&build.errors);
+    }
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).to_path_buf();
+    fs::write(out.join("awards.rs"), awards).unwrap();
+    fs::write(out.join("popular.rs"), popular).unwrap();
+}
+
+impl Build {
+    fn manifest<T: DeserializeOwned>(&self, manifest: &str) -> Vec<T> {
+        println!("cargo:rerun-if-changed={}", manifest);
+        let json = fs::read_to_string(self.dir.join(manifest))
+            .unwrap_or_else(|e| fail(&[format!("{}: can't be read: {}", manifest, e)]));
+        let entries: Vec<T> =
+            serde_json::from_str(&json).unwrap_or_else(|e| fail(&[format!("{}: {}", manifest, e)]));
+        if entries.is_empty() {
+            fail(&[format!("{}: no movies are listed", manifest)]);
+        }
+        entries
+    }
+
+    /// An `include_str!` of `file` in data/, checking it has some text.
+    fn text(&mut self, at: &str, file: &str) -> String {
+        let path = self.dir.join("data").join(file);
+        println!("cargo:rerun-if-changed={}", path.display());
+        match fs::read_to_string(&path) {
+            Ok(text) if text.trim().is_empty() => {
+                self.errors.push(format!("{}: data/{} is empty", at, file))
+            }
+            Ok(_) => {}
+            Err(e) => self
+                .errors
+                .push(format!("{}: data/{} can't be read: {}", at, file, e)),
+        }
+        format!("include_str!({:?})", path)
+    }
+
+    /// The name of the catalog movie `title` stands for: `movie` if given,
+    /// otherwise the movie named like the title.
+    fn movie(&mut self, at: &str, title: &str, movie: &Option<Option<String>>) -> Option<String> {
+        match movie {
+            Some(None) => None,
+            Some(Some(name)) => {
+                if !self.catalog.contains(name) {
+                    let message = format!("{}: no movie of {} is named {:?}", at, CATALOG, name);
+                    self.errors.push(message);
+                }
+                Some(name.clone())
+            }
+            None => {
+                let found = self
+                    .catalog
+                    .iter()
+                    .find(|name| name.eq_ignore_ascii_case(title));
+                if found.is_none() {
+                    println!(
+                        "cargo:warning={}: matches no movie of {}; name it with \"movie\", \
+                         or set \"movie\": null if it isn't in the catalog",
+                        at, CATALOG
+                    );
+                }
+                found.cloned()
+            }
+        }
+    }
+
+    fn title(&mut self, at: &str, title: &str, titles: &mut HashSet<String>) {
+        if title.trim().is_empty() {
+            self.errors.push(format!("{}: the title is empty", at));
+        } else if !titles.insert(title.trim().to_lowercase()) {
+            self.errors
+                .push(format!("{}: the title is used by an earlier entry", at));
+        }
+    }
+
+    fn awards(&mut self) -> String {
+        let entries: Vec<AwardEntry> = self.manifest(AWARDS);
+        let mut titles = HashSet::new();
+        let mut table = String::from("pub static AWARDS: &[Award] = &[\n");
+        for (i, entry) in entries.iter().enumerate() {
+            let at = format!("{}: award {} ({:?})", AWARDS, i + 1, entry.title);
+            self.title(&at, &entry.title, &mut titles);
+            for (field, url) in [("trailer", &entry.trailer), ("img", &entry.img)] {
+                if !url.starts_with("https://") {
+                    let message = format!("{}: {} {:?} is not an https url", at, field, url);
+                    self.errors.push(message);
+                }
+            }
+            let movie = self.movie(&at, &entry.title, &entry.movie);
+            let results = self.results(&at, &entry.results);
+            self.told(&at, &entry.won, &entry.results);
+            writeln!(
+                table,
+                "    Award {{ title: {:?}, movie: {:?}, description: {}, won: {}, \
+                 trailer: {:?}, img: {:?}, results: &[{}] }},",
+                entry.title.trim(),
+                movie,
+                self.text(&at, &entry.about),
+                self.text(&at, &entry.won),
+                entry.trailer,
+                entry.img,
+                results,
+            )
+            .unwrap();
+        }
+        table.push_str("];\n");
+        table
+    }
+
+    fn results(&mut self, at: &str, ceremonies: &[Ceremony]) -> String {
+        let mut results = String::new();
+        for ceremony in ceremonies {
+            let at = format!("{}: {} {}", at, ceremony.ceremony, ceremony.year);
+            let mut error = |message: &str| self.errors.push(format!("{}: {}", at, message));
+            if ceremony.ceremony.trim().is_empty() {
+                error("the ceremony has no name");
+            }
+            if !(1900..=2100).contains(&ceremony.year) {
+                error("the year is out of range");
+            }
+            if ceremony.edition == Some(0) {
+                error("editions start at 1");
+            }
+            if ceremony.won.is_empty() && ceremony.nominated.is_empty() {
+                error("no category is listed");
+            }
+            let mut categories = HashSet::new();
+            let outcomes = (ceremony.won.iter().map(|c| (c, "Won")))
+                .chain(ceremony.nominated.iter().map(|c| (c, "Nominated")));
+            for (category, outcome) in outcomes {
+                if category.trim().is_empty() {
+                    error("a category is empty");
+                } else if !categories.insert(category.trim()) {
+                    error(&format!("{:?} is listed twice", category));
+                }
+                write!(
+                    results,
+                    "AwardResult {{ ceremony: {:?}, edition: {:?}, year: {}, category: {:?}, \
+                     outcome: Outcome::{} }}, ",
+                    ceremony.ceremony.trim(),
+                    ceremony.edition,
+                    ceremony.year,
+                    category.trim(),
+                    outcome,
+                )
+                .unwrap();
+            }
+        }
+        results
+    }
+
+    /// Checks every category won is named somewhere in the prose of `won`,
+    /// which catches a misspelt category. It doesn't check which ceremony the
+    /// prose names it for, nor that the prose names every win.
+    fn told(&mut self, at: &str, won: &str, ceremonies: &[Ceremony]) {
+        let Ok(prose) = fs::read_to_string(self.dir.join("data").join(won)) else {
+            return; // Reported by `text`.
+        };
+        let prose = plain(&prose);
+        for ceremony in ceremonies {
+            for category in &ceremony.won {
+                if !prose.contains(&plain(category)) {
+                    self.errors.push(format!(
+                        "{}: {} {}: {:?} was won but isn't mentioned in data/{}",
+                        at, ceremony.ceremony, ceremony.year, category, won
+                    ));
+                }
+            }
+        }
+    }
+
+    fn popular(&mut self) -> String {
+        let entries: Vec<PopularEntry> = self.manifest(POPULAR);
+        let mut titles = HashSet::new();
+        let mut table = String::from("pub static POPULAR: &[Popular] = &[\n");
+        for (i, entry) in entries.iter().enumerate() {
+            let at = format!("{}: movie {} ({:?})", POPULAR, i + 1, entry.title);
+            self.title(&at, &entry.title, &mut titles);
+            let movie = self.movie(&at, &entry.title, &entry.movie);
+            writeln!(
+                table,
+                "    Popular {{ title: {:?}, movie: {:?}, genre: {:?}, runtime: {}, \
+                 description: {}, actors: {}, gross: {} }},",
+                entry.title.trim(),
+                movie,
+                entry.genre,
+                entry.runtime,
+                self.text(&at, &entry.description),
+                self.text(&at, &entry.actors),
+                self.text(&at, &entry.gross),
+            )
+            .unwrap();
+        }
+        table.push_str("];\n");
+        table
+    }
+}
+
+/// The name at the start of a row of IMDb.txt, e.g. `Heat` in
+/// `{ "Heat", "Action", "8.3", "Al Pacino, Robert De Niro" } ,`.
+fn movie_name(line: &str) -> Option<String> {
+    let rest = line
+        .trim_start()
+        .strip_prefix('{')?
+        .trim_start()
+        .strip_prefix('"')?;
+    let mut name = String::new();
+    let mut chars = rest.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => name.extend(chars.next()),
+            '"' => return Some(name),
+            c => name.push(c),
+        }
+    }
+    None
+}
+
+/// `text` in lowercase with typographic apostrophes made plain, for comparing
+/// category names with prose.
+fn plain(text: &str) -> String {
+    text.to_lowercase().replace('\u{2019}', "'")
+}
+
+fn fail(errors: &[String]) -> ! {
+    for error in errors {
+        eprintln!("error: {}", error);
+    }
+    process::exit(1);
+}
@@ -0,0 +1,172 @@
+//! Actors as they appear across the catalog, for the `/actor/:name` pages.
+
+use std::collections::BTreeMap;
+
+use crate::model::{Genre, Movie, Rating};
+use crate::slug::slug;
+
+/// `name` trimmed, with runs of whitespace collapsed to a single space.
+pub fn normalize(name: &str) -> String {
+    name.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// The id of an actor in urls, e.g. `zoe-kravitz`. Spellings that only differ
+/// in case, accents or punctuation share an id. A name with no latin letter or
+/// digit, such as "三船敏郎", gets `actor-` and a hash of the name instead, so
+/// such actors still have a page of their own.
+pub fn actor_id(name: &str) -> String {
+    match slug(name) {
+        s if s.is_empty() => format!("actor-{:08x}", fnv1a(&normalize(name).to_lowercase())),
+        s => s,
+    }
+}
+
+/// The 32-bit FNV-1a hash, which unlike `DefaultHasher` is the same in every
+/// build, so the ids in bookmarked urls keep working.
+fn fnv1a(text: &str) -> u32 {
+    text.bytes().fold(0x811c_9dc5, |hash, b| {
+        (hash ^ u32::from(b)).wrapping_mul(0x0100_0193)
+    })
+}
+
+/// The spellings of one actor across the catalog. The actor goes by the one
+/// used most often, the one seen first on a tie, both in their
+/// [`Filmography`] and in the [`crate::graph::CoStarGraph`].
+#[derive(Debug, Clone, Default)]
+pub struct Spellings(Vec<(String, usize)>);
+
+impl Spellings {
+    pub fn add(&mut self, name: &str) {
+        match self.0.iter_mut().find(|(spelling, _)| spelling == name) {
+            Some((_, n)) => *n += 1,
+            None => self.0.push((name.to_string(), 1)),
+        }
+    }
+
+    /// The spelling the actor goes by, `None` if none was added.
+    pub fn name(&self) -> Option<&str> {
+        let best = self
+            .0
+            .iter()
+            .reduce(|best, s| if s.1 > best.1 { s } else { best });
+        best.map(|(spelling, _)| spelling.as_str())
+    }
+}
+
+/// The [`actor_id`] of every actor of every movie, in the order of `movies`.
+/// Worth computing once per catalog and passing to [`filmography`] and
+/// [`crate::similar::similar`].
+pub fn actor_ids<M: AsRef<Movie>>(movies: &[M]) -> Vec<Vec<String>> {
+    let ids = |movie: &Movie| movie.actors.iter().map(|a| actor_id(a)).collect();
+    movies.iter().map(|m| ids(m.as_ref())).collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filmography {
+    /// The spelling the actor goes by, see [`Spellings`].
+    pub name: String,
+    /// Positions of the actor's movies in the catalog, best rated first.
+    pub movies: Vec<usize>,
+    pub average: Rating,
+    /// How many of the movies have each genre, most frequent first.
+    pub genres: Vec<(Genre, usize)>,
+}
+
+/// Every movie of `movies` the actor with the id `id` plays in, or `None` if
+/// there is no such actor. `actor_ids` are the [`actor_ids`] of `movies`.
+pub fn filmography<M: AsRef<Movie>, A: AsRef<[String]>>(
+    movies: &[M],
+    actor_ids: &[A],
+    id: &str,
+) -> Option<Filmography> {
+    let mut spellings = Spellings::default();
+    let mut ids = Vec::new();
+    for (i, (movie, cast)) in movies.iter().zip(actor_ids).enumerate() {
+        let mut cast = movie.as_ref().actors.iter().zip(cast.as_ref());
+        if let Some((name, _)) = cast.find(|(_, actor)| *actor == id) {
+            spellings.add(name);
+            ids.push(i);
+        }
+    }
+    let name = spellings.name()?.to_string();
+    ids.sort_by_key(|&i| std::cmp::Reverse(movies[i].as_ref().rating));
+
+    let mut genres: BTreeMap<Genre, usize> = BTreeMap::new();
+    for &i in &ids {
+        *genres.entry(movies[i].as_ref().genre).or_default() += 1;
+    }
+    let mut genres: Vec<(Genre, usize)> = genres.into_iter().collect();
+    genres.sort_by_key(|&(_, n)| std::cmp::Reverse(n));
+
+    Some(Filmography {
+        name,
+        average: Rating::mean(ids.iter().map(|&i| movies[i].as_ref().rating))?,
+        movies: ids,
+        genres,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::movie;
+
+    #[test]
+    fn names_are_normalized() {
+        assert_eq!(normalize("  Robert  Downey Jr. "), "Robert Downey Jr.");
+        assert_eq!(actor_id("Zoë Kravitz"), actor_id("zoe kravitz"));
+        assert_eq!(actor_id("Robert Downey Jr."), "robert-downey-jr");
+    }
+
+    #[test]
+    fn names_without_latin_letters_still_get_an_id() {
+        let mifune = actor_id("三船敏郎");
+        assert!(mifune.starts_with("actor-"), "{}", mifune);
+        assert_eq!(actor_id(" 三船  敏郎 "), actor_id("三船 敏郎"));
+        assert_ne!(mifune, actor_id("志村喬"));
+        assert_eq!(actor_id("Тоширо"), actor_id("тоширо"));
+    }
+
+    #[test]
+    fn the_most_used_spelling_wins_then_the_first() {
+        let mut spellings = Spellings::default();
+        assert_eq!(spellings.name(), None);
+        spellings.add("Zoe Kravitz");
+        spellings.add("Zoë Kravitz");
+        assert_eq!(spellings.name(), Some("Zoe Kravitz"));
+        spellings.add("Zoë Kravitz");
+        assert_eq!(spellings.name(), Some("Zoë Kravitz"));
+    }
+
+    #[test]
+    fn filmography_collects_every_spelling() {
+        let movies = [
+            movie(
+                "Mad Max: Fury Road",
+                Genre::Action,
+                "8.1",
+                &["Tom Hardy", "Zoë Kravitz"],
+            ),
+            movie(
+                "The Batman",
+                Genre::Action,
+                "7.8",
+                &["Robert Pattinson", "Zoë Kravitz"],
+            ),
+            movie(
+                "High Fidelity",
+                Genre::Comedy,
+                "7.4",
+                &["John Cusack", "Zoe Kravitz"],
+            ),
+            movie("Heat", Genre::Action, "8.3", &["Al Pacino"]),
+        ];
+        let ids = actor_ids(&movies);
+        let film = filmography(&movies, &ids, "zoe-kravitz").unwrap();
+        assert_eq!(film.name, "Zoë Kravitz");
+        assert_eq!(film.movies, [0, 1, 2]);
+        assert_eq!(film.average, "7.8".parse().unwrap());
+        assert_eq!(film.genres, [(Genre::Action, 2), (Genre::Comedy, 1)]);
+        assert_eq!(filmography(&movies, &ids, "kevin-bacon"), None);
+    }
+}
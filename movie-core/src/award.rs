@@ -0,0 +1,245 @@
+//! The award-winning movies of the Awards tab. They are listed in
+//! data/awards.json, which the build script checks and turns into [`AWARDS`].
+
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct Award {
+    pub title: &'static str,
+    /// The name of the movie in the catalog, `None` if it isn't there.
+    pub movie: Option<&'static str>,
+    pub description: &'static str,
+    pub won: &'static str,
+    pub trailer: &'static str,
+    pub img: &'static str,
+    /// Every category the movie won or was nominated in, ceremony by ceremony.
+    pub results: &'static [AwardResult],
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Outcome {
+    Won,
+    Nominated,
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct AwardResult {
+    pub ceremony: &'static str,
+    /// `94` for the 94th Academy Awards, `None` where editions aren't counted.
+    pub edition: Option<u16>,
+    pub year: u16,
+    pub category: &'static str,
+    pub outcome: Outcome,
+}
+
+include!(concat!(env!("OUT_DIR"), "/awards.rs"));
+
+impl Award {
+    /// The award of the catalog movie named `name`, if it has one.
+    pub fn of_movie(name: &str) -> Option<&'static Award> {
+        AWARDS.iter().find(|a| a.movie == Some(name))
+    }
+
+    pub fn wins(&self) -> impl Iterator<Item = &AwardResult> {
+        self.results.iter().filter(|r| r.outcome == Outcome::Won)
+    }
+}
+
+impl AwardResult {
+    /// The ceremony and its year, e.g. "94th Academy Awards, 2022".
+    pub fn event(&self) -> String {
+        match self.edition {
+            Some(edition) => format!("{} {}, {}", ordinal(edition), self.ceremony, self.year),
+            None => format!("{}, {}", self.ceremony, self.year),
+        }
+    }
+}
+
+/// `1st`, `2nd`, `3rd`, `4th`, ..., `11th`, ..., `92nd`.
+pub fn ordinal(n: u16) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+/// Narrows the awards down to a ceremony and a category; `None` lets any through.
+#[derive(PartialEq, Eq, Clone, Debug, Default)]
+pub struct AwardFilter {
+    pub ceremony: Option<String>,
+    pub category: Option<String>,
+}
+
+impl AwardFilter {
+    pub fn matches_result(&self, result: &AwardResult) -> bool {
+        let ceremony = self.ceremony.as_deref();
+        let category = self.category.as_deref();
+        ceremony.is_none_or(|c| c == result.ceremony)
+            && category.is_none_or(|c| c == result.category)
+    }
+
+    /// Whether any single result of `award` passes both the ceremony and the category.
+    pub fn matches(&self, award: &Award) -> bool {
+        award.results.iter().any(|r| self.matches_result(r))
+    }
+}
+
+/// Every ceremony of `awards` once, in alphabetical order.
+pub fn ceremonies(awards: &[Award]) -> Vec<&'static str> {
+    let all = awards.iter().flat_map(|a| a.results).map(|r| r.ceremony);
+    all.collect::<BTreeSet<_>>().into_iter().collect()
+}
+
+/// Every category of `awards` at `ceremony`, or at any ceremony, in alphabetical order.
+pub fn categories(awards: &[Award], ceremony: Option<&str>) -> Vec<&'static str> {
+    let all = awards.iter().flat_map(|a| a.results);
+    all.filter(|r| ceremony.is_none_or(|c| c == r.ceremony))
+        .map(|r| r.category)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// The number of wins of every award, most first, keeping the manifest order between ties.
+pub fn wins_per_film(awards: &[Award]) -> Vec<(&Award, usize)> {
+    let mut wins: Vec<_> = awards.iter().map(|a| (a, a.wins().count())).collect();
+    wins.sort_by_key(|&(_, wins)| std::cmp::Reverse(wins));
+    wins
+}
+
+/// The `n` categories won most often across `awards`, alphabetically between ties.
+pub fn most_awarded_categories(awards: &[Award], n: usize) -> Vec<(&'static str, usize)> {
+    let mut counts: BTreeMap<&'static str, usize> = BTreeMap::new();
+    for result in awards.iter().flat_map(|a| a.wins()) {
+        *counts.entry(result.category).or_default() += 1;
+    }
+    let mut counts: Vec<_> = counts.into_iter().collect();
+    counts.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+    counts.truncate(n);
+    counts
+}
+
+pub const BEST_PICTURE: &str = "Best Picture";
+
+/// The Best Picture wins of `awards` from the earliest year on.
+pub fn best_picture_timeline(awards: &[Award]) -> Vec<(&Award, &AwardResult)> {
+    let mut wins: Vec<_> = (awards.iter())
+        .flat_map(|a| a.wins().map(move |r| (a, r)))
+        .filter(|(_, r)| r.category == BEST_PICTURE)
+        .collect();
+    wins.sort_by_key(|(_, r)| r.year);
+    wins
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::{self, IMDB_DATA};
+    use crate::model::Movie;
+
+    #[test]
+    fn manifest_is_complete() {
+        assert_eq!(AWARDS[0].title, "Dune");
+        assert!(AWARDS
+            .iter()
+            .all(|a| !a.description.trim().is_empty() && !a.won.trim().is_empty()));
+        let dune = &AWARDS[0];
+        assert_eq!(dune.wins().count(), 6);
+        assert_eq!(dune.results.len(), 10);
+        assert_eq!(dune.results[0].event(), "94th Academy Awards, 2022");
+    }
+
+    #[test]
+    fn movies_are_in_the_catalog() {
+        let movies: Vec<Movie> = catalog::parse(IMDB_DATA).map(Result::unwrap).collect();
+        for name in AWARDS.iter().filter_map(|a| a.movie) {
+            assert!(movies.iter().any(|m| m.name == name), "{}", name);
+        }
+        assert_eq!(AWARDS[0].movie, Some("Dune: Part One"));
+        assert!(AWARDS.iter().all(|a| a.movie.is_some()));
+        assert_eq!(
+            Award::of_movie("Dune: Part One").map(|a| a.title),
+            Some("Dune")
+        );
+        assert_eq!(Award::of_movie("Dune"), None);
+    }
+
+    #[test]
+    fn ordinals() {
+        let ordinals: Vec<String> = [1, 2, 3, 4, 11, 12, 13, 21, 72, 92, 94, 111]
+            .into_iter()
+            .map(ordinal)
+            .collect();
+        assert_eq!(
+            ordinals,
+            [
+                "1st", "2nd", "3rd", "4th", "11th", "12th", "13th", "21st", "72nd", "92nd", "94th",
+                "111th"
+            ]
+        );
+    }
+
+    #[test]
+    fn filter_needs_one_result_matching_both() {
+        let titles = |filter: &AwardFilter| -> Vec<&str> {
+            AWARDS
+                .iter()
+                .filter(|a| filter.matches(a))
+                .map(|a| a.title)
+                .collect()
+        };
+        assert_eq!(titles(&AwardFilter::default()).len(), AWARDS.len());
+        let palme = AwardFilter {
+            ceremony: None,
+            category: Some("Palme d'Or".to_string()),
+        };
+        assert_eq!(titles(&palme), ["Parasite"]);
+        // Parasite went to the Academy Awards, but won the Palme d'Or at Cannes.
+        let academy_palme = AwardFilter {
+            ceremony: Some("Academy Awards".to_string()),
+            ..palme
+        };
+        assert!(titles(&academy_palme).is_empty());
+    }
+
+    #[test]
+    fn statistics() {
+        let wins = wins_per_film(AWARDS);
+        assert_eq!(wins.len(), AWARDS.len());
+        assert_eq!((wins[0].0.title, wins[0].1), ("Parasite", 8));
+        assert!(wins.windows(2).all(|w| w[0].1 >= w[1].1));
+
+        let categories = most_awarded_categories(AWARDS, 3);
+        // King Richard's Best Actor wins at four ceremonies tie with the Best Pictures.
+        assert_eq!(categories[..2], [("Best Actor", 4), (BEST_PICTURE, 4)]);
+        assert_eq!(categories.len(), 3);
+
+        let timeline: Vec<(u16, &str)> = best_picture_timeline(AWARDS)
+            .into_iter()
+            .map(|(a, r)| (r.year, a.title))
+            .collect();
+        assert_eq!(
+            timeline,
+            [
+                (2002, "A beautiful mind"),
+                (2018, "The shape of water"),
+                (2020, "Parasite"),
+                (2022, "Coda"),
+            ]
+        );
+    }
+
+    #[test]
+    fn ceremonies_and_categories_are_listed_once() {
+        let ceremonies = ceremonies(AWARDS);
+        assert!(ceremonies.contains(&"Academy Awards"));
+        assert!(ceremonies.windows(2).all(|w| w[0] < w[1]));
+        let cannes = categories(AWARDS, Some("Cannes Film Festival"));
+        assert_eq!(cannes, ["Palme d'Or"]);
+        assert!(categories(AWARDS, None).len() > cannes.len());
+    }
+}
@@ -0,0 +1,258 @@
+use std::fmt;
+use std::io;
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+use crate::model::{parse_actors, InvalidRating, Movie, UnknownGenre};
+
+pub const IMDB_DATA: &str = include_str!("../data/IMDb.txt");
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<csv::Error> for ParseError {
+    fn from(e: csv::Error) -> Self {
+        let line = e.position().map_or(0, |p| p.line() as usize);
+        let message = match e.kind() {
+            csv::ErrorKind::Deserialize { err, .. } => err.to_string(),
+            _ => e.to_string(),
+        };
+        ParseError { line, message }
+    }
+}
+
+/// Parses every non-blank line of the catalog. A row looks like
+/// `{ "name", "genre", "rating", "actors" } ,` where the trailing comma is optional.
+pub fn parse(src: &str) -> impl Iterator<Item = Result<Movie, ParseError>> + '_ {
+    src.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            parse_line(line).map_err(|message| ParseError {
+                line: i + 1,
+                message,
+            })
+        })
+}
+
+fn parse_line(line: &str) -> Result<Movie, String> {
+    let mut chars = line.char_indices().peekable();
+
+    skip_whitespace(&mut chars);
+    expect(&mut chars, '{')?;
+
+    let mut fields = Vec::with_capacity(4);
+    loop {
+        skip_whitespace(&mut chars);
+        fields.push(quoted(&mut chars)?);
+        skip_whitespace(&mut chars);
+        match chars.next() {
+            Some((_, ',')) => continue,
+            Some((_, '}')) => break,
+            Some((i, c)) => return Err(format!("unexpected '{}' at column {}", c, i + 1)),
+            None => return Err("row is missing its closing '}'".to_string()),
+        }
+    }
+
+    skip_whitespace(&mut chars);
+    if let Some((_, ',')) = chars.peek() {
+        chars.next();
+    }
+    skip_whitespace(&mut chars);
+    if let Some((i, c)) = chars.next() {
+        return Err(format!("unexpected '{}' after row at column {}", c, i + 1));
+    }
+
+    let [name, genre, rating, actors]: [String; 4] = fields
+        .try_into()
+        .map_err(|f: Vec<String>| format!("expected 4 fields, found {}", f.len()))?;
+
+    if name.trim().is_empty() {
+        return Err("movie name is empty".to_string());
+    }
+
+    Ok(Movie {
+        name,
+        genre: genre.parse().map_err(|e: UnknownGenre| e.to_string())?,
+        rating: rating.parse().map_err(|e: InvalidRating| e.to_string())?,
+        actors: parse_actors(&actors),
+    })
+}
+
+fn skip_whitespace(chars: &mut Peekable<CharIndices>) {
+    while let Some((_, c)) = chars.peek() {
+        if !c.is_whitespace() {
+            break;
+        }
+        chars.next();
+    }
+}
+
+fn expect(chars: &mut Peekable<CharIndices>, expected: char) -> Result<(), String> {
+    match chars.next() {
+        Some((_, c)) if c == expected => Ok(()),
+        Some((i, c)) => Err(format!(
+            "expected '{}' at column {}, found '{}'",
+            expected,
+            i + 1,
+            c
+        )),
+        None => Err(format!("expected '{}', found end of line", expected)),
+    }
+}
+
+fn quoted(chars: &mut Peekable<CharIndices>) -> Result<String, String> {
+    expect(chars, '"')?;
+    let mut value = String::new();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Ok(value),
+            '\\' => match chars.next() {
+                Some((_, e @ ('"' | '\\'))) => value.push(e),
+                _ => return Err(format!("invalid escape at column {}", i + 1)),
+            },
+            _ => value.push(c),
+        }
+    }
+    Err("unterminated string".to_string())
+}
+
+/// Reads a catalog in CSV form. The first record must be the header
+/// `name,genre,rating,actors`; columns are matched by name, so their order is free.
+/// `rating` is a number like `7.9` and `actors` is a single comma separated cell.
+pub fn read_csv<R: io::Read>(reader: R) -> Result<Vec<Movie>, ParseError> {
+    csv::Reader::from_reader(reader)
+        .deserialize()
+        .map(|row| row.map_err(ParseError::from))
+        .collect()
+}
+
+/// Writes movies as CSV with the header expected by [`read_csv`].
+pub fn write_csv<'a, W: io::Write>(
+    writer: W,
+    movies: impl IntoIterator<Item = &'a Movie>,
+) -> Result<(), csv::Error> {
+    let mut csv = csv::Writer::from_writer(writer);
+    for movie in movies {
+        csv.serialize(movie)?;
+    }
+    csv.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Genre;
+
+    #[test]
+    fn imdb_data_parses_without_errors() {
+        let movies: Vec<Movie> = parse(IMDB_DATA).collect::<Result<_, _>>().unwrap();
+        assert!(movies.len() > 5000);
+        assert!(movies
+            .iter()
+            .any(|m| m.name == "Mujeres al borde de un ataque de \"nervios\""));
+    }
+
+    #[test]
+    fn malformed_rows_report_their_line() {
+        let src = "{ \"Heat\", \"Action\", \"8.3\", \"Al Pacino\" } ,\n\n{ \"Heat\", \"Action\" }\n{ \"Heat\", \"Action\", \"eight\", \"Al Pacino\" }";
+        let errors: Vec<usize> = parse(src).filter_map(|r| r.err()).map(|e| e.line).collect();
+        assert_eq!(errors, [3, 4]);
+    }
+
+    #[test]
+    fn malformed_rows_say_what_is_wrong() {
+        let error = |row: &str| parse(row).next().unwrap().unwrap_err().to_string();
+        assert_eq!(
+            error(r#"  "Heat", "Action", "8.3", "Al Pacino" }"#),
+            "line 1: expected '{' at column 3, found '\"'"
+        );
+        assert_eq!(
+            error(r#"{ "Heat", "Action", "8.3", "Al Pacino" "#),
+            "line 1: row is missing its closing '}'"
+        );
+        assert_eq!(
+            error(r#"{ "Heat", "Action", "8.3", "Al Pacino }"#),
+            "line 1: unterminated string"
+        );
+        assert_eq!(
+            error(r#"{ "Heat"; "Action", "8.3", "Al Pacino" }"#),
+            "line 1: unexpected ';' at column 9"
+        );
+        assert_eq!(
+            error(r#"{ "Heat", "Action", "8.3", "Al Pacino" } , x"#),
+            "line 1: unexpected 'x' after row at column 44"
+        );
+        assert_eq!(
+            error(r#"{ "He\at", "Action", "8.3", "Al Pacino" }"#),
+            "line 1: invalid escape at column 6"
+        );
+        assert_eq!(
+            error(r#"{ "Heat", "Action", "8.3" }"#),
+            "line 1: expected 4 fields, found 3"
+        );
+        assert_eq!(
+            error(r#"{ " ", "Action", "8.3", "Al Pacino" }"#),
+            "line 1: movie name is empty"
+        );
+        assert_eq!(
+            error(r#"{ "Heat", "Sci-Fi", "8.3", "Al Pacino" }"#),
+            "line 1: unknown genre \"Sci-Fi\""
+        );
+        assert_eq!(
+            error(r#"{ "Heat", "Action", "11", "Al Pacino" }"#),
+            "line 1: rating \"11\" is not a number between 0 and 10"
+        );
+    }
+
+    #[test]
+    fn line_numbers_count_blank_lines_and_skip_nothing_else() {
+        let good = r#"{ "Heat", "Action", "8.3", "Al Pacino" } ,"#;
+        let src = format!("{}\n\n   \n{{ \"Ran\" }}\n{}\n{{\n", good, good);
+        let rows: Vec<Result<String, usize>> = parse(&src)
+            .map(|r| r.map(|m| m.name).map_err(|e| e.line))
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                Ok("Heat".to_string()),
+                Err(4),
+                Ok("Heat".to_string()),
+                Err(6)
+            ]
+        );
+    }
+
+    #[test]
+    fn csv_round_trips() {
+        let movies: Vec<Movie> = parse(IMDB_DATA).take(50).map(Result::unwrap).collect();
+        let mut csv = Vec::new();
+        write_csv(&mut csv, &movies).unwrap();
+        assert!(csv.starts_with(b"name,genre,rating,actors\n"));
+        assert_eq!(read_csv(&csv[..]).unwrap(), movies);
+    }
+
+    #[test]
+    fn csv_errors_carry_the_line() {
+        let csv = "name,genre,rating,actors\nHeat,Action,8.3,\"Al Pacino, Robert De Niro\"\nBad,Sci-Fi,7,x\n";
+        let err = read_csv(csv.as_bytes()).unwrap_err();
+        assert_eq!(err.line, 3);
+        assert_eq!(err.message, "unknown genre \"Sci-Fi\"");
+
+        let movies = read_csv(&csv.as_bytes()[..csv.find("Bad").unwrap()]).unwrap();
+        assert_eq!(movies[0].genre, Genre::Action);
+        assert_eq!(movies[0].actors, ["Al Pacino", "Robert De Niro"]);
+    }
+}
@@ -0,0 +1,53 @@
+//! Layout of the SVG charts of the award statistics, in SVG user units.
+
+/// The width of a bar for each of `values`, the largest taking all of `width`.
+/// Values above zero get at least one unit so they don't vanish.
+pub fn bar_widths(values: &[usize], width: u32) -> Vec<u32> {
+    let max = values.iter().copied().max().unwrap_or(0).max(1) as u64;
+    values
+        .iter()
+        .map(|&v| match v {
+            0 => 0,
+            v => ((v as u64 * u64::from(width) / max) as u32).max(1),
+        })
+        .collect()
+}
+
+/// Where each of `years` goes on an axis running from `from` to `to`, the
+/// earliest year at `from` and the latest at `to`. A single year sits in the middle.
+pub fn year_positions(years: &[u16], from: u32, to: u32) -> Vec<u32> {
+    let (Some(&first), Some(&last)) = (years.iter().min(), years.iter().max()) else {
+        return Vec::new();
+    };
+    let span = u32::from(last - first);
+    years
+        .iter()
+        .map(|&year| match span {
+            0 => from + (to - from) / 2,
+            span => from + u32::from(year - first) * (to - from) / span,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bars_scale_to_the_largest_value() {
+        assert_eq!(bar_widths(&[8, 4, 1, 0], 400), [400, 200, 50, 0]);
+        assert_eq!(bar_widths(&[1000, 1], 100), [100, 1]);
+        assert_eq!(bar_widths(&[0, 0], 100), [0, 0]);
+        assert!(bar_widths(&[], 100).is_empty());
+    }
+
+    #[test]
+    fn years_spread_over_the_axis() {
+        assert_eq!(
+            year_positions(&[2002, 2018, 2020, 2022], 50, 550),
+            [50, 450, 500, 550]
+        );
+        assert_eq!(year_positions(&[2020, 2020], 0, 100), [50, 50]);
+        assert!(year_positions(&[], 0, 100).is_empty());
+    }
+}
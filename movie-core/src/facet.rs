@@ -0,0 +1,272 @@
+//! Facets of the library: how many of the movies the search matches have each
+//! genre or rating, and the genres and ratings picked to narrow the results down.
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::model::{Genre, InvalidRating, Movie, Rating, UnknownGenre};
+
+/// The picked genres. A movie passes if it has any of them, or if none is picked.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct GenreFacet {
+    picked: BTreeSet<Genre>,
+}
+
+impl GenreFacet {
+    pub fn is_empty(&self) -> bool {
+        self.picked.is_empty()
+    }
+
+    pub fn contains(&self, genre: Genre) -> bool {
+        self.picked.contains(&genre)
+    }
+
+    pub fn toggle(&mut self, genre: Genre) {
+        if !self.picked.remove(&genre) {
+            self.picked.insert(genre);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.picked.clear();
+    }
+
+    pub fn matches(&self, movie: &Movie) -> bool {
+        self.is_empty() || self.contains(movie.genre)
+    }
+
+    /// Reads a url like [`FromStr`], but skips the genres it doesn't know,
+    /// returning them next to the facet of the others.
+    pub fn parse_known(s: &str) -> (Self, Vec<UnknownGenre>) {
+        let mut facet = GenreFacet::default();
+        let mut unknown = Vec::new();
+        for genre in s.split(',').filter(|g| !g.trim().is_empty()) {
+            match genre.parse() {
+                Ok(genre) => {
+                    facet.picked.insert(genre);
+                }
+                Err(e) => unknown.push(e),
+            }
+        }
+        (facet, unknown)
+    }
+}
+
+/// The picked genres as they appear in urls, e.g. `action,film-noir`.
+impl fmt::Display for GenreFacet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, genre) in self.picked.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            f.write_str(&genre.as_str().to_ascii_lowercase())?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for GenreFacet {
+    type Err = UnknownGenre;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let picked = s
+            .split(',')
+            .filter(|g| !g.trim().is_empty())
+            .map(str::parse)
+            .collect::<Result<_, _>>()?;
+        Ok(GenreFacet { picked })
+    }
+}
+
+/// How many of the movies with a score in `scores` have each genre, in
+/// [`Genre::ALL`] order. The picked genres don't change the counts, so they
+/// tell how many movies picking one more genre would add.
+pub fn genre_counts<M: AsRef<Movie>>(movies: &[M], scores: &[Option<u32>]) -> Vec<(Genre, usize)> {
+    let mut counts = Genre::ALL.map(|genre| (genre, 0));
+    for (movie, score) in movies.iter().zip(scores) {
+        if score.is_some() {
+            let genre = movie.as_ref().genre;
+            if let Some((_, count)) = counts.iter_mut().find(|(g, _)| *g == genre) {
+                *count += 1;
+            }
+        }
+    }
+    counts.to_vec()
+}
+
+/// Ratings from `min` to `max`, both included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RatingRange {
+    pub min: Rating,
+    pub max: Rating,
+}
+
+impl Default for RatingRange {
+    fn default() -> Self {
+        RatingRange {
+            min: Rating::default(),
+            max: Rating::MAX,
+        }
+    }
+}
+
+impl RatingRange {
+    /// The lowest to the highest rating of `movies`, every rating if there are none.
+    pub fn spanning<M: AsRef<Movie>>(movies: &[M]) -> RatingRange {
+        let ratings = movies.iter().map(|m| m.as_ref().rating);
+        match (ratings.clone().min(), ratings.max()) {
+            (Some(min), Some(max)) => RatingRange { min, max },
+            _ => RatingRange::default(),
+        }
+    }
+
+    pub fn contains(&self, rating: Rating) -> bool {
+        (self.min..=self.max).contains(&rating)
+    }
+
+    pub fn matches(&self, movie: &Movie) -> bool {
+        self.contains(movie.rating)
+    }
+
+    /// Whether the range leaves out any of the ratings in `span`.
+    pub fn narrows(&self, span: RatingRange) -> bool {
+        self.min > span.min || self.max < span.max
+    }
+}
+
+/// The range as it appears in urls, e.g. `8-8.5`.
+impl fmt::Display for RatingRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.min, self.max)
+    }
+}
+
+impl FromStr for RatingRange {
+    type Err = InvalidRating;
+
+    /// Swapped bounds are put back in order.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (min, max) = s
+            .split_once('-')
+            .ok_or_else(|| InvalidRating(s.to_string()))?;
+        let (min, max): (Rating, Rating) = (min.parse()?, max.parse()?);
+        Ok(RatingRange {
+            min: min.min(max),
+            max: min.max(max),
+        })
+    }
+}
+
+/// How many of the `ratings` fall on each tenth of `span`, from `span.min` up.
+/// Ratings outside of `span` aren't counted.
+pub fn rating_histogram(
+    ratings: impl IntoIterator<Item = Rating>,
+    span: RatingRange,
+) -> Vec<usize> {
+    let (min, max) = (span.min.tenths(), span.max.tenths());
+    let mut counts = vec![0; usize::from(max.saturating_sub(min)) + 1];
+    for rating in ratings.into_iter().filter(|&r| span.contains(r)) {
+        counts[usize::from(rating.tenths() - min)] += 1;
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::{self, IMDB_DATA};
+    use crate::index::SearchIndex;
+    use crate::query::Query;
+
+    #[test]
+    fn facet_round_trips_through_urls() {
+        let facet: GenreFacet = "western, Film-Noir,".parse().unwrap();
+        assert!(facet.contains(Genre::FilmNoir) && facet.contains(Genre::Western));
+        assert_eq!(facet.to_string(), "film-noir,western");
+        assert_eq!(facet.to_string().parse(), Ok(facet));
+        assert_eq!("".parse(), Ok(GenreFacet::default()));
+        assert!("action,sci-fi".parse::<GenreFacet>().is_err());
+    }
+
+    #[test]
+    fn unknown_genres_are_skipped_one_by_one() {
+        let (facet, unknown) = GenreFacet::parse_known("drama,foo,,western,sci-fi");
+        assert_eq!(facet.to_string(), "drama,western");
+        assert_eq!(
+            unknown,
+            [
+                UnknownGenre("foo".to_string()),
+                UnknownGenre("sci-fi".to_string())
+            ]
+        );
+        assert_eq!(
+            GenreFacet::parse_known("foo"),
+            (GenreFacet::default(), vec![UnknownGenre("foo".to_string())])
+        );
+    }
+
+    #[test]
+    fn picked_genres_combine_with_or() {
+        let movies: Vec<Movie> = catalog::parse(IMDB_DATA).map(Result::unwrap).collect();
+        let mut facet = GenreFacet::default();
+        assert!(movies.iter().all(|m| facet.matches(m)));
+        facet.toggle(Genre::Western);
+        facet.toggle(Genre::Crime);
+        let picked = movies.iter().filter(|m| facet.matches(m)).count();
+        let counts = genre_counts(&movies, &vec![Some(0); movies.len()]);
+        let count = |genre| counts.iter().find(|(g, _)| *g == genre).unwrap().1;
+        assert_eq!(picked, count(Genre::Western) + count(Genre::Crime));
+        facet.toggle(Genre::Crime);
+        assert!(!facet.contains(Genre::Crime));
+    }
+
+    #[test]
+    fn rating_ranges_include_both_bounds() {
+        let rating = |r: &str| r.parse::<Rating>().unwrap();
+        let range: RatingRange = "8.5-8".parse().unwrap();
+        assert_eq!((range.min, range.max), (rating("8"), rating("8.5")));
+        assert_eq!(range.to_string(), "8-8.5");
+        assert!(range.contains(rating("8")) && range.contains(rating("8.5")));
+        assert!(!range.contains(rating("8.6")));
+        assert!("8".parse::<RatingRange>().is_err());
+        assert!("8-11".parse::<RatingRange>().is_err());
+    }
+
+    #[test]
+    fn histogram_counts_every_tenth_of_the_span() {
+        let movies: Vec<Movie> = catalog::parse(IMDB_DATA).map(Result::unwrap).collect();
+        let span = RatingRange::spanning(&movies);
+        assert!(!RatingRange::default().narrows(span));
+        let histogram = rating_histogram(movies.iter().map(|m| m.rating), span);
+        assert_eq!(
+            histogram.len(),
+            usize::from(span.max.tenths() - span.min.tenths()) + 1
+        );
+        assert_eq!(histogram.iter().sum::<usize>(), movies.len());
+        assert!(histogram[0] > 0 && histogram[histogram.len() - 1] > 0);
+
+        let narrow: RatingRange = "8-8.5".parse().unwrap();
+        let narrowed = rating_histogram(movies.iter().map(|m| m.rating), narrow);
+        let in_range = movies.iter().filter(|m| narrow.matches(m)).count();
+        assert_eq!(narrowed.len(), 6);
+        assert_eq!(narrowed.iter().sum::<usize>(), in_range);
+    }
+
+    #[test]
+    fn counts_follow_the_query() {
+        let movies: Vec<Movie> = catalog::parse(IMDB_DATA).map(Result::unwrap).collect();
+        let all = genre_counts(&movies, &vec![Some(0); movies.len()]);
+        assert_eq!(all.iter().map(|(_, n)| n).sum::<usize>(), movies.len());
+
+        let index = SearchIndex::new(movies);
+        let query = Query::parse("genre:western").unwrap();
+        let counts = genre_counts(index.movies(), &index.scores(&query));
+        for (genre, count) in counts {
+            match genre {
+                Genre::Western => assert!(count > 0),
+                _ => assert_eq!(count, 0, "{}", genre),
+            }
+        }
+    }
+}
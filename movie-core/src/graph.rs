@@ -0,0 +1,254 @@
+//! The co-star graph: actors are nodes and two actors are linked by every
+//! movie of the catalog they play in together.
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+use crate::actor::{actor_id, Spellings};
+use crate::model::Movie;
+
+pub struct CoStarGraph {
+    /// Display name of every actor, in order of first appearance in the
+    /// catalog. The name is the spelling the actor goes by, see [`Spellings`].
+    names: Vec<String>,
+    /// [`actor_id`] → position in `names`.
+    nodes: HashMap<String, usize>,
+    /// For every actor, the co-stars and the positions of their shared movies.
+    edges: Vec<BTreeMap<usize, Vec<usize>>>,
+}
+
+/// A chain of co-stars: `movies[i]` has both `actors[i]` and `actors[i + 1]` in its cast.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Chain {
+    pub actors: Vec<String>,
+    pub movies: Vec<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Collaborator {
+    pub name: String,
+    /// Positions of the shared movies in the catalog.
+    pub movies: Vec<usize>,
+}
+
+impl CoStarGraph {
+    pub fn new<M: AsRef<Movie>>(movies: &[M]) -> Self {
+        let mut graph = CoStarGraph {
+            names: Vec::new(),
+            nodes: HashMap::new(),
+            edges: Vec::new(),
+        };
+        let mut spellings: Vec<Spellings> = Vec::new();
+        for (position, movie) in movies.iter().enumerate() {
+            let mut cast: Vec<usize> = movie
+                .as_ref()
+                .actors
+                .iter()
+                .map(|name| {
+                    let node = graph.node(name);
+                    if node == spellings.len() {
+                        spellings.push(Spellings::default());
+                    }
+                    spellings[node].add(name);
+                    node
+                })
+                .collect();
+            cast.sort_unstable();
+            cast.dedup();
+            for &a in &cast {
+                for &b in cast.iter().filter(|&&b| b != a) {
+                    graph.edges[a].entry(b).or_default().push(position);
+                }
+            }
+        }
+        graph.names = (spellings.iter())
+            .map(|s| s.name().unwrap_or_default().to_string())
+            .collect();
+        graph
+    }
+
+    fn node(&mut self, name: &str) -> usize {
+        let next = self.edges.len();
+        let node = *self.nodes.entry(actor_id(name)).or_insert(next);
+        if node == next {
+            self.edges.push(BTreeMap::new());
+        }
+        node
+    }
+
+    /// The display name of every actor.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// The display name of the actor with the id `id`.
+    pub fn name(&self, id: &str) -> Option<&str> {
+        self.nodes.get(id).map(|&n| self.names[n].as_str())
+    }
+
+    /// The shortest chain of shared movies from one actor to another, `None`
+    /// if either is unknown or nothing connects them. Among equally short chains
+    /// the one through the earliest actors and movies of the catalog wins.
+    pub fn chain(&self, from: &str, to: &str) -> Option<Chain> {
+        let (&from, &to) = (self.nodes.get(from)?, self.nodes.get(to)?);
+        let mut came_from: Vec<Option<usize>> = vec![None; self.names.len()];
+        let mut queue = VecDeque::from([from]);
+        came_from[from] = Some(from);
+        while let Some(node) = queue.pop_front() {
+            if node == to {
+                break;
+            }
+            for &next in self.edges[node].keys() {
+                if came_from[next].is_none() {
+                    came_from[next] = Some(node);
+                    queue.push_back(next);
+                }
+            }
+        }
+        came_from[to]?;
+
+        let mut path = vec![to];
+        while *path.last().unwrap() != from {
+            path.push(came_from[*path.last().unwrap()].unwrap());
+        }
+        path.reverse();
+        Some(Chain {
+            movies: path
+                .windows(2)
+                .map(|w| self.edges[w[0]][&w[1]][0])
+                .collect(),
+            actors: path.into_iter().map(|n| self.names[n].clone()).collect(),
+        })
+    }
+
+    /// The `n` actors sharing the most movies with the actor with the id `id`,
+    /// ties in catalog order.
+    pub fn collaborators(&self, id: &str, n: usize) -> Vec<Collaborator> {
+        let Some(&node) = self.nodes.get(id) else {
+            return Vec::new();
+        };
+        let mut costars: Vec<(&usize, &Vec<usize>)> = self.edges[node].iter().collect();
+        costars.sort_by_key(|(_, movies)| std::cmp::Reverse(movies.len()));
+        costars
+            .into_iter()
+            .take(n)
+            .map(|(&costar, movies)| Collaborator {
+                name: self.names[costar].clone(),
+                movies: movies.clone(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::{self, IMDB_DATA};
+    use crate::model::{movie, Genre};
+
+    fn movies() -> Vec<Movie> {
+        vec![
+            movie(
+                "Star Wars",
+                Genre::Action,
+                "8",
+                &["Mark Hamill", "Harrison Ford", "Carrie Fisher"],
+            ),
+            movie(
+                "Blade Runner",
+                Genre::Action,
+                "8",
+                &["Harrison Ford", "Rutger Hauer", "Sean Young"],
+            ),
+            movie(
+                "The Empire Strikes Back",
+                Genre::Action,
+                "8",
+                &["Mark Hamill", "Harrison Ford"],
+            ),
+            movie(
+                "Ladyhawke",
+                Genre::Action,
+                "8",
+                &["Rutger Hauer", "Michelle Pfeiffer"],
+            ),
+            movie("Heat", Genre::Action, "8", &["Al Pacino", "Robert De Niro"]),
+        ]
+    }
+
+    #[test]
+    fn chain_is_the_shortest_path() {
+        let graph = CoStarGraph::new(&movies());
+        let chain = graph.chain("mark-hamill", "michelle-pfeiffer").unwrap();
+        assert_eq!(
+            chain.actors,
+            [
+                "Mark Hamill",
+                "Harrison Ford",
+                "Rutger Hauer",
+                "Michelle Pfeiffer"
+            ]
+        );
+        assert_eq!(chain.movies, [0, 1, 3]);
+
+        assert_eq!(graph.chain("nobody", "al-pacino"), None);
+        assert_eq!(graph.chain("al-pacino", "mark-hamill"), None);
+        assert!(graph
+            .chain("al-pacino", "al-pacino")
+            .unwrap()
+            .movies
+            .is_empty());
+    }
+
+    #[test]
+    fn collaborators_are_ordered_by_shared_movies() {
+        let graph = CoStarGraph::new(&movies());
+        let names: Vec<(String, usize)> = graph
+            .collaborators("harrison-ford", 3)
+            .into_iter()
+            .map(|c| (c.name, c.movies.len()))
+            .collect();
+        assert_eq!(
+            names,
+            [
+                ("Mark Hamill".to_string(), 2),
+                ("Carrie Fisher".to_string(), 1),
+                ("Rutger Hauer".to_string(), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn actors_go_by_the_spelling_of_their_filmography() {
+        let movies = [
+            movie(
+                "Heat",
+                Genre::Crime,
+                "8.3",
+                &["Al  Pacino", "Robert De Niro"],
+            ),
+            movie("Serpico", Genre::Crime, "7.7", &["Al Pacino"]),
+            movie("Scarface", Genre::Crime, "8.3", &["Al Pacino"]),
+            movie("The Irishman", Genre::Crime, "7.8", &["Robert de Niro"]),
+        ];
+        let graph = CoStarGraph::new(&movies);
+        let ids = crate::actor::actor_ids(&movies);
+        for id in ["al-pacino", "robert-de-niro"] {
+            let film = crate::actor::filmography(&movies, &ids, id).unwrap();
+            assert_eq!(graph.name(id), Some(film.name.as_str()));
+        }
+        assert_eq!(graph.name("al-pacino"), Some("Al Pacino"));
+        assert_eq!(graph.name("robert-de-niro"), Some("Robert De Niro"));
+    }
+
+    #[test]
+    fn catalog_links_kurosawa_and_nolan_regulars() {
+        let movies: Vec<Movie> = catalog::parse(IMDB_DATA).map(Result::unwrap).collect();
+        let graph = CoStarGraph::new(&movies);
+        let chain = graph.chain("toshiro-mifune", "michael-caine").unwrap();
+        for (i, &m) in chain.movies.iter().enumerate() {
+            let cast: Vec<String> = movies[m].actors.iter().map(|a| actor_id(a)).collect();
+            assert!(cast.contains(&actor_id(&chain.actors[i])));
+            assert!(cast.contains(&actor_id(&chain.actors[i + 1])));
+        }
+    }
+}
@@ -0,0 +1,193 @@
+//! An inverted index over the catalog, so a keystroke in the search box only
+//! scores the movies that can possibly match instead of the whole catalog.
+
+use std::collections::BTreeMap;
+
+use crate::model::Movie;
+use crate::query::Query;
+use crate::search::{self, FoldedMovie};
+
+/// Owns the movies it indexes. A movie's id is its position in [`SearchIndex::movies`].
+pub struct SearchIndex<M> {
+    movies: Vec<M>,
+    folded: Vec<FoldedMovie>,
+    /// Folded word → ids of the movies using it, in ascending order.
+    postings: BTreeMap<String, Vec<usize>>,
+}
+
+impl<M: AsRef<Movie>> SearchIndex<M> {
+    pub fn new(movies: Vec<M>) -> Self {
+        let folded: Vec<FoldedMovie> = movies.iter().map(|m| m.as_ref().into()).collect();
+        let mut postings: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+        for (id, movie) in folded.iter().enumerate() {
+            let fields = [&movie.name, &movie.genre].into_iter().chain(&movie.actors);
+            for word in fields.flat_map(|f| search::words(f)) {
+                let ids = postings.entry(word.to_string()).or_default();
+                if ids.last() != Some(&id) {
+                    ids.push(id);
+                }
+            }
+        }
+        SearchIndex {
+            movies,
+            folded,
+            postings,
+        }
+    }
+
+    pub fn movies(&self) -> &[M] {
+        &self.movies
+    }
+
+    pub fn len(&self) -> usize {
+        self.movies.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.movies.is_empty()
+    }
+
+    /// The score of every movie for `query`, indexed by id, with `None` for
+    /// movies that don't match. Gives the same results as [`Query::score`].
+    pub fn scores(&self, query: &Query) -> Vec<Option<u32>> {
+        let mut scores = vec![None; self.movies.len()];
+        let candidates = match self.candidates(query) {
+            Some(ids) => ids,
+            None => (0..self.movies.len()).collect(),
+        };
+        for id in candidates {
+            scores[id] = query.score_folded(self.movies[id].as_ref(), &self.folded[id]);
+        }
+        scores
+    }
+
+    /// A superset of the ids matching `query`, or `None` if the index can't
+    /// narrow it down and every movie has to be checked.
+    fn candidates(&self, query: &Query) -> Option<Vec<usize>> {
+        match query {
+            Query::Text(t) | Query::Name(t) | Query::Genre(t) | Query::Actor(t) => {
+                self.term_candidates(t)
+            }
+            Query::And(qs) => qs
+                .iter()
+                .filter_map(|q| self.candidates(q))
+                .reduce(|a, b| intersect(&a, &b)),
+            Query::Or(qs) => qs
+                .iter()
+                .map(|q| self.candidates(q))
+                .collect::<Option<Vec<_>>>()
+                .map(|sets| sets.iter().fold(Vec::new(), |a, b| union(&a, b))),
+            Query::All | Query::Rating(..) | Query::Not(_) => None,
+        }
+    }
+
+    /// Every word of a matching text has to contain the search word or be within
+    /// the typos [`search::score`] allows, so only those words' postings are
+    /// marked. Marking costs one pass over the postings however many words
+    /// match, which matters for the first letter or two typed.
+    fn term_candidates(&self, term: &str) -> Option<Vec<usize>> {
+        let wanted_words = search::words(term);
+        if wanted_words.is_empty() {
+            return None;
+        }
+        let mut matched = vec![true; self.movies.len()];
+        for wanted in &wanted_words {
+            let len = wanted.chars().count();
+            let allowed = search::allowed_typos(len);
+            let mut hit = vec![false; self.movies.len()];
+            for (word, posting) in &self.postings {
+                let close = || {
+                    allowed > 0
+                        && word.chars().count().abs_diff(len) <= allowed
+                        && search::edit_distance(wanted, word) <= allowed
+                };
+                if word.contains(wanted) || close() {
+                    for &id in posting {
+                        hit[id] = true;
+                    }
+                }
+            }
+            for (matched, hit) in matched.iter_mut().zip(hit) {
+                *matched &= hit;
+            }
+        }
+        Some((0..matched.len()).filter(|&id| matched[id]).collect())
+    }
+}
+
+fn intersect(a: &[usize], b: &[usize]) -> Vec<usize> {
+    let (mut i, mut j, mut out) = (0, 0, Vec::new());
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                out.push(a[i]);
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    out
+}
+
+fn union(a: &[usize], b: &[usize]) -> Vec<usize> {
+    let (mut i, mut j, mut out) = (0, 0, Vec::with_capacity(a.len() + b.len()));
+    while i < a.len() || j < b.len() {
+        match (a.get(i), b.get(j)) {
+            (Some(x), Some(y)) if x == y => {
+                out.push(*x);
+                i += 1;
+                j += 1;
+            }
+            (Some(x), Some(y)) if x < y => {
+                out.push(*x);
+                i += 1;
+            }
+            (Some(x), None) => {
+                out.push(*x);
+                i += 1;
+            }
+            (_, Some(y)) => {
+                out.push(*y);
+                j += 1;
+            }
+            (None, None) => unreachable!(),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SearchIndex;
+    use crate::catalog::{self, IMDB_DATA};
+    use crate::model::Movie;
+    use crate::query::Query;
+
+    #[test]
+    fn index_agrees_with_scoring_every_movie() {
+        let movies: Vec<Movie> = catalog::parse(IMDB_DATA).map(Result::unwrap).collect();
+        let index = SearchIndex::new(movies);
+        for query in [
+            "",
+            "tom hardy",
+            "zoe kravits",
+            "yojinbo",
+            "vitz",
+            "t",
+            "to",
+            "mi ha",
+            "max: fury",
+            "actor:\"Harrison Ford\" OR genre:western",
+            "rating:>=8.5 -action",
+            "(kurosawa OR mifune) drama",
+            "-",
+        ] {
+            let query = Query::parse(query).unwrap();
+            let expected: Vec<Option<u32>> =
+                index.movies().iter().map(|m| query.score(m)).collect();
+            assert_eq!(index.scores(&query), expected, "{:?}", query);
+        }
+    }
+}
@@ -0,0 +1,25 @@
+//! Catalog, search and sorting logic of the movie library, kept free of any
+//! browser dependency so it can be tested with a plain `cargo test`.
+
+pub mod actor;
+pub mod award;
+pub mod catalog;
+pub mod chart;
+pub mod facet;
+pub mod graph;
+pub mod index;
+pub mod lists;
+pub mod model;
+pub mod params;
+pub mod popular;
+pub mod query;
+pub mod review;
+pub mod search;
+pub mod similar;
+pub mod slug;
+pub mod sort;
+pub mod trailer;
+pub mod transition;
+pub mod userdata;
+pub mod viewport;
+pub mod watchlist;
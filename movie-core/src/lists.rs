@@ -0,0 +1,272 @@
+//! Named, ordered lists of movies such as "Kurosawa marathon". The app saves
+//! them as part of [`crate::userdata::UserData`] and shares single lists as JSON files.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::watchlist::Entry;
+
+/// Version of the exported list files.
+pub const FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomList {
+    pub name: String,
+    pub movies: Vec<Entry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ListError {
+    EmptyName,
+    DuplicateName(String),
+}
+
+impl fmt::Display for ListError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ListError::EmptyName => write!(f, "a list needs a name"),
+            ListError::DuplicateName(name) => {
+                write!(f, "there already is a list called \"{}\"", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ListError {}
+
+/// Why an exported list file can't be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormatError {
+    Json(String),
+    UnsupportedVersion(u32),
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatError::Json(e) => write!(f, "the list is not valid json: {}", e),
+            FormatError::UnsupportedVersion(v) => {
+                write!(f, "the list has the unsupported version {}", v)
+            }
+        }
+    }
+}
+
+impl std::error::Error for FormatError {}
+
+/// A list exported on its own: `{"version":1,"name":"...","movies":[...]}`.
+#[derive(Serialize, Deserialize)]
+struct Exported {
+    version: u32,
+    #[serde(flatten)]
+    list: CustomList,
+}
+
+fn check_version(json: &str) -> Result<(), FormatError> {
+    #[derive(Deserialize)]
+    struct Version {
+        version: u32,
+    }
+    match serde_json::from_str::<Version>(json) {
+        Ok(Version {
+            version: FORMAT_VERSION,
+        }) => Ok(()),
+        Ok(Version { version }) => Err(FormatError::UnsupportedVersion(version)),
+        Err(e) => Err(FormatError::Json(e.to_string())),
+    }
+}
+
+impl CustomList {
+    pub fn contains(&self, id: &str) -> bool {
+        self.movies.iter().any(|e| e.id == id)
+    }
+
+    /// Moves the movie at `from` to `to`, shifting the ones in between.
+    pub fn move_movie(&mut self, from: usize, to: usize) {
+        if from < self.movies.len() && to < self.movies.len() {
+            let entry = self.movies.remove(from);
+            self.movies.insert(to, entry);
+        }
+    }
+
+    /// Takes the movie at `at` off the list; an index past the end, e.g. one
+    /// shown before another tab shortened the list, removes nothing.
+    pub fn remove_movie(&mut self, at: usize) -> Option<Entry> {
+        (at < self.movies.len()).then(|| self.movies.remove(at))
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&Exported {
+            version: FORMAT_VERSION,
+            list: self.clone(),
+        })
+        .expect("a list always serializes")
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, FormatError> {
+        check_version(json)?;
+        serde_json::from_str::<Exported>(json)
+            .map(|e| e.list)
+            .map_err(|e| FormatError::Json(e.to_string()))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Lists {
+    lists: Vec<CustomList>,
+}
+
+impl Lists {
+    pub fn lists(&self) -> &[CustomList] {
+        &self.lists
+    }
+
+    pub fn get(&self, i: usize) -> Option<&CustomList> {
+        self.lists.get(i)
+    }
+
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut CustomList> {
+        self.lists.get_mut(i)
+    }
+
+    fn check_name(&self, name: &str, except: Option<usize>) -> Result<String, ListError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(ListError::EmptyName);
+        }
+        let taken = self
+            .lists
+            .iter()
+            .enumerate()
+            .any(|(i, l)| Some(i) != except && l.name.eq_ignore_ascii_case(name));
+        match taken {
+            true => Err(ListError::DuplicateName(name.to_string())),
+            false => Ok(name.to_string()),
+        }
+    }
+
+    /// Adds an empty list and returns its position.
+    pub fn create(&mut self, name: &str) -> Result<usize, ListError> {
+        let name = self.check_name(name, None)?;
+        self.lists.push(CustomList {
+            name,
+            movies: Vec::new(),
+        });
+        Ok(self.lists.len() - 1)
+    }
+
+    pub fn rename(&mut self, i: usize, name: &str) -> Result<(), ListError> {
+        let name = self.check_name(name, Some(i))?;
+        if let Some(list) = self.lists.get_mut(i) {
+            list.name = name;
+        }
+        Ok(())
+    }
+
+    pub fn delete(&mut self, i: usize) {
+        if i < self.lists.len() {
+            self.lists.remove(i);
+        }
+    }
+
+    /// Adds an imported list, numbering its name if it is taken:
+    /// a second "Bollywood night" becomes "Bollywood night (2)".
+    pub fn import(&mut self, mut list: CustomList) -> Result<usize, ListError> {
+        let base = self.check_name(&list.name, None).or_else(|e| match e {
+            ListError::DuplicateName(name) => Ok(name),
+            e => Err(e),
+        })?;
+        list.name = base.clone();
+        let mut n = 2;
+        while self.check_name(&list.name, None).is_err() {
+            list.name = format!("{} ({})", base, n);
+            n += 1;
+        }
+        self.lists.push(list);
+        Ok(self.lists.len() - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: &str) -> Entry {
+        Entry {
+            id: id.to_string(),
+            name: id.to_string(),
+        }
+    }
+
+    #[test]
+    fn names_must_be_unique_and_not_empty() {
+        let mut lists = Lists::default();
+        assert_eq!(lists.create("Kurosawa marathon"), Ok(0));
+        assert_eq!(lists.create("  "), Err(ListError::EmptyName));
+        assert_eq!(
+            lists.create("kurosawa MARATHON"),
+            Err(ListError::DuplicateName("kurosawa MARATHON".to_string()))
+        );
+        assert_eq!(lists.create("Bollywood night"), Ok(1));
+        assert!(lists.rename(1, "Kurosawa marathon").is_err());
+        assert_eq!(lists.rename(0, " Kurosawa Marathon "), Ok(()));
+        assert_eq!(lists.get(0).unwrap().name, "Kurosawa Marathon");
+        lists.delete(0);
+        assert_eq!(lists.lists().len(), 1);
+    }
+
+    #[test]
+    fn movies_can_be_reordered() {
+        let mut list = CustomList {
+            name: "a".to_string(),
+            movies: vec![entry("ran"), entry("ikiru"), entry("yojimbo")],
+        };
+        list.move_movie(2, 0);
+        let ids: Vec<&str> = list.movies.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, ["yojimbo", "ran", "ikiru"]);
+        list.move_movie(0, 7);
+        assert_eq!(list.movies[0].id, "yojimbo");
+    }
+
+    #[test]
+    fn removing_past_the_end_does_nothing() {
+        let mut list = CustomList {
+            name: "a".to_string(),
+            movies: vec![entry("ran"), entry("ikiru")],
+        };
+        assert_eq!(list.remove_movie(1), Some(entry("ikiru")));
+        assert_eq!(list.remove_movie(1), None);
+        assert_eq!(list.movies, [entry("ran")]);
+    }
+
+    #[test]
+    fn exported_lists_import_under_a_free_name() {
+        let mut lists = Lists::default();
+        lists.create("Bollywood night").unwrap();
+        let list = CustomList {
+            name: "Bollywood night".to_string(),
+            movies: vec![entry("lagaan")],
+        };
+        let json = list.to_json();
+        assert!(json.contains(r#""version": 1"#));
+        let imported = CustomList::from_json(&json).unwrap();
+        assert_eq!(imported, list);
+        assert_eq!(lists.import(imported.clone()), Ok(1));
+        assert_eq!(lists.import(imported), Ok(2));
+        let names: Vec<&str> = lists.lists().iter().map(|l| l.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "Bollywood night",
+                "Bollywood night (2)",
+                "Bollywood night (3)"
+            ]
+        );
+        assert_eq!(
+            CustomList::from_json(r#"{"version":2,"name":"x","movies":[]}"#),
+            Err(FormatError::UnsupportedVersion(2))
+        );
+    }
+}
@@ -0,0 +1,258 @@
+use crate::actor;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize)]
+pub struct Movie {
+    pub name: String,
+    pub genre: Genre,
+    pub rating: Rating,
+    #[serde(with = "actor_list")]
+    pub actors: Vec<String>,
+}
+
+impl AsRef<Movie> for Movie {
+    fn as_ref(&self) -> &Movie {
+        self
+    }
+}
+
+/// Variants are kept in alphabetical order so that sorting by genre matches sorting by name.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
+pub enum Genre {
+    Action,
+    Adventure,
+    Animation,
+    Biography,
+    Comedy,
+    Crime,
+    Drama,
+    Fantasy,
+    FilmNoir,
+    Horror,
+    Musical,
+    Mystery,
+    Western,
+}
+
+impl Genre {
+    pub const ALL: [Genre; 13] = [
+        Genre::Action,
+        Genre::Adventure,
+        Genre::Animation,
+        Genre::Biography,
+        Genre::Comedy,
+        Genre::Crime,
+        Genre::Drama,
+        Genre::Fantasy,
+        Genre::FilmNoir,
+        Genre::Horror,
+        Genre::Musical,
+        Genre::Mystery,
+        Genre::Western,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Genre::Action => "Action",
+            Genre::Adventure => "Adventure",
+            Genre::Animation => "Animation",
+            Genre::Biography => "Biography",
+            Genre::Comedy => "Comedy",
+            Genre::Crime => "Crime",
+            Genre::Drama => "Drama",
+            Genre::Fantasy => "Fantasy",
+            Genre::FilmNoir => "Film-Noir",
+            Genre::Horror => "Horror",
+            Genre::Musical => "Musical",
+            Genre::Mystery => "Mystery",
+            Genre::Western => "Western",
+        }
+    }
+}
+
+impl fmt::Display for Genre {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct UnknownGenre(pub String);
+
+impl fmt::Display for UnknownGenre {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown genre \"{}\"", self.0)
+    }
+}
+
+impl std::error::Error for UnknownGenre {}
+
+impl FromStr for Genre {
+    type Err = UnknownGenre;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Genre::ALL
+            .iter()
+            .find(|g| g.as_str().eq_ignore_ascii_case(s.trim()))
+            .copied()
+            .ok_or_else(|| UnknownGenre(s.to_string()))
+    }
+}
+
+/// IMDb score out of ten, stored in tenths so it can be ordered and compared exactly.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug, Default)]
+pub struct Rating(u8);
+
+impl Rating {
+    pub const MAX: Rating = Rating(100);
+
+    /// `None` above ten.
+    pub fn from_tenths(tenths: u8) -> Option<Rating> {
+        (tenths <= Rating::MAX.0).then_some(Rating(tenths))
+    }
+
+    /// The score in tenths, `79` for 7.9.
+    pub fn tenths(self) -> u8 {
+        self.0
+    }
+
+    /// The average of `ratings` rounded to a tenth, `None` if there are none.
+    pub fn mean(ratings: impl IntoIterator<Item = Rating>) -> Option<Rating> {
+        let (sum, count) = ratings.into_iter().fold((0u32, 0u32), |(sum, count), r| {
+            (sum + u32::from(r.0), count + 1)
+        });
+        (count > 0).then(|| Rating(((sum + count / 2) / count) as u8))
+    }
+}
+
+/// Prints the score the way IMDb does: `8`, `7.9`.
+impl fmt::Display for Rating {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 % 10 {
+            0 => write!(f, "{}", self.0 / 10),
+            d => write!(f, "{}.{}", self.0 / 10, d),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct InvalidRating(pub String);
+
+impl fmt::Display for InvalidRating {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "rating \"{}\" is not a number between 0 and 10", self.0)
+    }
+}
+
+impl std::error::Error for InvalidRating {}
+
+impl FromStr for Rating {
+    type Err = InvalidRating;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.trim()
+            .parse::<f32>()
+            .ok()
+            .filter(|r| (0.0..=10.0).contains(r))
+            .map(|r| Rating((r * 10.0).round() as u8))
+            .ok_or_else(|| InvalidRating(s.to_string()))
+    }
+}
+
+/// Splits the comma separated cast list used by IMDb.txt.
+pub fn parse_actors(s: &str) -> Vec<String> {
+    s.split(',')
+        .map(actor::normalize)
+        .filter(|a| !a.is_empty())
+        .collect()
+}
+
+mod actor_list {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(actors: &[String], s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&actors.join(", "))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Vec<String>, D::Error> {
+        Ok(super::parse_actors(&String::deserialize(d)?))
+    }
+}
+
+macro_rules! serde_via_str {
+    ($($ty:ty),+) => {$(
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+                s.collect_str(self)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+                String::deserialize(d)?.parse().map_err(de::Error::custom)
+            }
+        }
+    )+};
+}
+
+serde_via_str!(Genre, Rating);
+
+/// A movie for the tests of the crate, e.g.
+/// `movie("Heat", Genre::Crime, "8.3", &["Al Pacino"])`.
+#[cfg(test)]
+pub(crate) fn movie(name: &str, genre: Genre, rating: &str, actors: &[&str]) -> Movie {
+    Movie {
+        name: name.to_string(),
+        genre,
+        rating: rating.parse().unwrap(),
+        actors: actors.iter().map(|a| a.to_string()).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_actors, Genre, Rating};
+
+    fn ratings(texts: &[&str]) -> Vec<Rating> {
+        texts.iter().map(|t| t.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn rating_round_trips_through_text() {
+        for text in ["8", "7.9", "10", "0.5"] {
+            assert_eq!(text.parse::<Rating>().unwrap().to_string(), text);
+        }
+        assert!("10.1".parse::<Rating>().is_err());
+        assert!("8 / 10".parse::<Rating>().is_err());
+        assert!("10".parse::<Rating>().unwrap() > "9.9".parse().unwrap());
+    }
+
+    #[test]
+    fn genre_parses_display_names() {
+        assert_eq!("Film-Noir".parse(), Ok(Genre::FilmNoir));
+        assert_eq!("comedy".parse(), Ok(Genre::Comedy));
+        assert!("Sci-Fi".parse::<Genre>().is_err());
+    }
+
+    #[test]
+    fn actors_split_on_commas() {
+        assert_eq!(
+            parse_actors("Robert Downey Jr., Gwyneth Paltrow,  Jeff Bridges"),
+            ["Robert Downey Jr.", "Gwyneth Paltrow", "Jeff Bridges"]
+        );
+        assert!(parse_actors("").is_empty());
+        assert_eq!(parse_actors(" Zoë   Kravitz ,"), ["Zoë Kravitz"]);
+    }
+
+    #[test]
+    fn mean_rounds_to_a_tenth() {
+        assert_eq!(
+            Rating::mean(ratings(&["8", "7.5"])),
+            Some(ratings(&["7.8"])[0])
+        );
+        assert_eq!(Rating::mean(ratings(&["8"])), Some(ratings(&["8"])[0]));
+        assert_eq!(Rating::mean(Vec::new()), None);
+    }
+}
@@ -0,0 +1,156 @@
+//! The part of the library state kept in the query string of /moviestab, e.g.
+//! `?q=actor%3Amifune&genre=drama,western&rating=8-8.5&sort=rating&dir=asc`,
+//! so searches can be bookmarked and shared. Defaults are left out.
+
+use serde::de::{DeserializeOwned, IntoDeserializer};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::facet::{GenreFacet, RatingRange};
+use crate::model::{InvalidRating, UnknownGenre};
+use crate::sort::{Direction, Sort};
+
+pub const DEFAULT_SORT: Sort = Sort::ByGenre;
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Debug, Default)]
+pub struct ListParams {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub q: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub genre: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub rating: String,
+    #[serde(
+        default,
+        deserialize_with = "known",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub sort: Option<Sort>,
+    #[serde(
+        default,
+        deserialize_with = "known",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub dir: Option<Direction>,
+}
+
+/// Reads an unknown sort or direction as the default, so a mistyped `sort`
+/// doesn't take the search and the filters of the url down with it.
+fn known<'de, D: Deserializer<'de>, T: DeserializeOwned>(d: D) -> Result<Option<T>, D::Error> {
+    let value = String::deserialize(d)?;
+    let value: serde::de::value::StrDeserializer<serde::de::value::Error> =
+        value.as_str().into_deserializer();
+    Ok(T::deserialize(value).ok())
+}
+
+impl ListParams {
+    /// The params of the library state, leaving out the genres, ratings and
+    /// sort the library starts with. `ratings` is `None` when it spans every rating.
+    pub fn new(
+        q: &str,
+        genres: &GenreFacet,
+        ratings: Option<RatingRange>,
+        sort: Sort,
+        direction: Direction,
+    ) -> Self {
+        ListParams {
+            q: q.to_string(),
+            genre: genres.to_string(),
+            rating: ratings.map(|r| r.to_string()).unwrap_or_default(),
+            sort: (sort != DEFAULT_SORT).then_some(sort),
+            dir: (direction != sort.default_direction()).then_some(direction),
+        }
+    }
+
+    /// The picked genres, with the ones the app doesn't know next to them.
+    pub fn genres(&self) -> (GenreFacet, Vec<UnknownGenre>) {
+        GenreFacet::parse_known(&self.genre)
+    }
+
+    /// The picked ratings, the default range if none are.
+    pub fn ratings(&self) -> Result<RatingRange, InvalidRating> {
+        match self.rating.is_empty() {
+            true => Ok(RatingRange::default()),
+            false => self.rating.parse(),
+        }
+    }
+
+    pub fn sort(&self) -> (Sort, Direction) {
+        let sort = self.sort.unwrap_or(DEFAULT_SORT);
+        (sort, self.dir.unwrap_or_else(|| sort.default_direction()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{Genre, Rating};
+
+    fn parse(query: &str) -> ListParams {
+        serde_urlencoded::from_str(query).unwrap()
+    }
+
+    #[test]
+    fn defaults_are_left_out() {
+        let params = ListParams::new(
+            "",
+            &GenreFacet::default(),
+            None,
+            DEFAULT_SORT,
+            DEFAULT_SORT.default_direction(),
+        );
+        assert_eq!(params, ListParams::default());
+        assert_eq!(serde_urlencoded::to_string(&params).unwrap(), "");
+        assert_eq!(parse("").sort(), (DEFAULT_SORT, Direction::Ascending));
+
+        // The rating sort goes from the highest down by default.
+        let params = ListParams::new(
+            "",
+            &GenreFacet::default(),
+            None,
+            Sort::ByRating,
+            Direction::Descending,
+        );
+        assert_eq!(serde_urlencoded::to_string(&params).unwrap(), "sort=rating");
+    }
+
+    #[test]
+    fn params_round_trip_through_the_query_string() {
+        let mut genres = GenreFacet::default();
+        genres.toggle(Genre::Drama);
+        genres.toggle(Genre::Western);
+        let ratings: RatingRange = "8-8.5".parse().unwrap();
+        let params = ListParams::new(
+            "actor:mifune",
+            &genres,
+            Some(ratings),
+            Sort::ByRating,
+            Direction::Ascending,
+        );
+        let query = serde_urlencoded::to_string(&params).unwrap();
+        assert_eq!(
+            query,
+            "q=actor%3Amifune&genre=drama%2Cwestern&rating=8-8.5&sort=rating&dir=asc"
+        );
+        let parsed = parse(&query);
+        assert_eq!(parsed, params);
+        assert_eq!(parsed.q, "actor:mifune");
+        assert_eq!(parsed.genres(), (genres, Vec::new()));
+        assert_eq!(parsed.ratings(), Ok(ratings));
+        assert_eq!(parsed.sort(), (Sort::ByRating, Direction::Ascending));
+    }
+
+    #[test]
+    fn malformed_params_fall_back_to_their_default() {
+        let params = parse("q=heat&genre=drama&rating=8-11&sort=length&dir=up");
+        assert_eq!(params.q, "heat");
+        assert_eq!(params.genres().0.to_string(), "drama");
+        assert!(params.ratings().is_err());
+        assert_eq!(params.sort(), (DEFAULT_SORT, Direction::Ascending));
+
+        let params = parse("sort=mine&dir=sideways&rating=");
+        assert_eq!(params.sort(), (Sort::ByMyRating, Direction::Descending));
+        assert_eq!(params.ratings(), Ok(RatingRange::default()));
+        let top = parse("rating=9-10").ratings().unwrap();
+        assert_eq!(top.min, "9".parse::<Rating>().unwrap());
+    }
+}
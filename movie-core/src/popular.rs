@@ -0,0 +1,32 @@
+//! The movies of the Popular tab. They are listed in data/popular.json, which
+//! the build script checks and turns into [`POPULAR`].
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct Popular {
+    pub title: &'static str,
+    /// The name of the movie in the catalog, `None` if it isn't there.
+    pub movie: Option<&'static str>,
+    pub genre: &'static str,
+    /// In minutes.
+    pub runtime: u16,
+    pub description: &'static str,
+    pub actors: &'static str,
+    pub gross: &'static str,
+}
+
+include!(concat!(env!("OUT_DIR"), "/popular.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::POPULAR;
+    use crate::catalog::{self, IMDB_DATA};
+    use crate::model::Movie;
+
+    #[test]
+    fn movies_are_in_the_catalog() {
+        let movies: Vec<Movie> = catalog::parse(IMDB_DATA).map(Result::unwrap).collect();
+        for name in POPULAR.iter().filter_map(|p| p.movie) {
+            assert!(movies.iter().any(|m| m.name == name), "{}", name);
+        }
+    }
+}
@@ -0,0 +1,447 @@
+//! The search language of the library.
+//!
+//! ```text
+//! actor:"Tom Hardy" genre:action rating:>=8 -genre:animation
+//! (kurosawa OR mifune) AND rating:>8
+//! ```
+//!
+//! Bare words and `"quoted phrases"` match the name, the genre or any actor.
+//! `name:`, `genre:` and `actor:` restrict a word or phrase to one field, and
+//! `rating:` takes a number optionally prefixed by `>`, `>=`, `<`, `<=` or `=`.
+//! Terms next to each other must all match; `OR` matches either side, `-` or
+//! `NOT` negates a term and parentheses group. `NOT` binds tighter than `AND`,
+//! which binds tighter than `OR`. Text is compared with [`search::score`], which
+//! ignores case and diacritics and forgives small typos.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::model::{Movie, Rating};
+use crate::search::{self, FoldedMovie};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Query {
+    All,
+    Text(String),
+    Name(String),
+    Genre(String),
+    Actor(String),
+    Rating(Comparison, Rating),
+    Not(Box<Query>),
+    And(Vec<Query>),
+    Or(Vec<Query>),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Comparison {
+    Less,
+    LessOrEqual,
+    Equal,
+    GreaterOrEqual,
+    Greater,
+}
+
+impl Comparison {
+    fn holds(&self, left: Rating, right: Rating) -> bool {
+        match self {
+            Comparison::Less => left < right,
+            Comparison::LessOrEqual => left <= right,
+            Comparison::Equal => left == right,
+            Comparison::GreaterOrEqual => left >= right,
+            Comparison::Greater => left > right,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct QueryError {
+    /// Character offset into the query the error points at.
+    pub position: usize,
+    pub message: String,
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (at character {})", self.message, self.position + 1)
+    }
+}
+
+impl std::error::Error for QueryError {}
+
+impl Query {
+    pub fn parse(src: &str) -> Result<Query, QueryError> {
+        let tokens = tokenize(src)?;
+        let mut parser = Parser {
+            tokens,
+            next: 0,
+            end: src.chars().count(),
+        };
+        if parser.tokens.is_empty() {
+            return Ok(Query::All);
+        }
+        let query = parser.or()?;
+        match parser.tokens.get(parser.next) {
+            None => Ok(query),
+            Some((pos, Token::RParen)) => Err(error(*pos, "unmatched ')'")),
+            Some((pos, _)) => Err(error(*pos, "unexpected term")),
+        }
+    }
+
+    pub fn matches(&self, movie: &Movie) -> bool {
+        self.score(movie).is_some()
+    }
+
+    /// How well `movie` matches, higher is better, or `None` when it doesn't match.
+    /// Conditions that hold without involving text, like a rating, score zero.
+    pub fn score(&self, movie: &Movie) -> Option<u32> {
+        self.score_folded(movie, &FoldedMovie::from(movie))
+    }
+
+    /// [`Query::score`] with the text fields of `movie` already folded.
+    pub fn score_folded(&self, movie: &Movie, folded: &FoldedMovie) -> Option<u32> {
+        match self {
+            Query::All => Some(0),
+            Query::Text(t) => search::movie_score(folded, t),
+            Query::Name(t) => search::score_folded(&folded.name, t),
+            Query::Genre(t) => search::score_folded(&folded.genre, t),
+            Query::Actor(t) => search::actor_score(folded, t),
+            Query::Rating(cmp, r) => cmp.holds(movie.rating, *r).then_some(0),
+            Query::Not(q) => match q.score_folded(movie, folded) {
+                Some(_) => None,
+                None => Some(0),
+            },
+            Query::And(qs) => qs.iter().map(|q| q.score_folded(movie, folded)).sum(),
+            Query::Or(qs) => qs
+                .iter()
+                .filter_map(|q| q.score_folded(movie, folded))
+                .max(),
+        }
+    }
+}
+
+impl FromStr for Query {
+    type Err = QueryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Query::parse(s)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum Token {
+    LParen,
+    RParen,
+    And,
+    Or,
+    Not,
+    Term {
+        field: Option<String>,
+        value: String,
+    },
+}
+
+fn error(position: usize, message: impl Into<String>) -> QueryError {
+    QueryError {
+        position,
+        message: message.into(),
+    }
+}
+
+const FIELDS: [&str; 5] = ["name", "genre", "actor", "actors", "rating"];
+
+fn tokenize(src: &str) -> Result<Vec<(usize, Token)>, QueryError> {
+    let chars: Vec<char> = src.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let start = i;
+        match chars[i] {
+            c if c.is_whitespace() => i += 1,
+            '(' => {
+                tokens.push((start, Token::LParen));
+                i += 1;
+            }
+            ')' => {
+                tokens.push((start, Token::RParen));
+                i += 1;
+            }
+            '-' if chars.get(i + 1).is_some_and(|c| !c.is_whitespace()) => {
+                tokens.push((start, Token::Not));
+                i += 1;
+            }
+            '"' => {
+                let (value, next) = quoted(&chars, i)?;
+                tokens.push((start, Token::Term { field: None, value }));
+                i = next;
+            }
+            _ => {
+                while i < chars.len() && !is_boundary(chars[i]) {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                let field = word
+                    .split_once(':')
+                    .map(|(f, _)| f.to_lowercase())
+                    .filter(|f| FIELDS.contains(&f.as_str()));
+                let token = match (field, word.as_str()) {
+                    (None, "AND") => Token::And,
+                    (None, "OR") => Token::Or,
+                    (None, "NOT") => Token::Not,
+                    (None, _) => Token::Term {
+                        field: None,
+                        value: word,
+                    },
+                    (Some(field), _) => {
+                        let mut value = word[field.len() + 1..].to_string();
+                        if value.is_empty() && chars.get(i) == Some(&'"') {
+                            let (quoted, next) = quoted(&chars, i)?;
+                            value = quoted;
+                            i = next;
+                        }
+                        if value.is_empty() {
+                            return Err(error(start, format!("'{}:' needs a value", field)));
+                        }
+                        Token::Term {
+                            field: Some(field),
+                            value,
+                        }
+                    }
+                };
+                tokens.push((start, token));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+fn is_boundary(c: char) -> bool {
+    c.is_whitespace() || matches!(c, '(' | ')' | '"')
+}
+
+/// Reads the phrase opened by the quote at `open`, returning it and the index after the closing quote.
+fn quoted(chars: &[char], open: usize) -> Result<(String, usize), QueryError> {
+    match chars[open + 1..].iter().position(|&c| c == '"') {
+        Some(len) => Ok((
+            chars[open + 1..open + 1 + len].iter().collect(),
+            open + len + 2,
+        )),
+        None => Err(error(open, "unterminated quote")),
+    }
+}
+
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    next: usize,
+    end: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.next).map(|(_, t)| t)
+    }
+
+    fn position(&self) -> usize {
+        self.tokens.get(self.next).map_or(self.end, |(p, _)| *p)
+    }
+
+    fn or(&mut self) -> Result<Query, QueryError> {
+        let mut alternatives = vec![self.and()?];
+        while self.peek() == Some(&Token::Or) {
+            self.next += 1;
+            alternatives.push(self.and()?);
+        }
+        Ok(flatten(alternatives, Query::Or))
+    }
+
+    fn and(&mut self) -> Result<Query, QueryError> {
+        let mut terms = vec![self.not()?];
+        loop {
+            match self.peek() {
+                Some(Token::And) => self.next += 1,
+                None | Some(Token::Or) | Some(Token::RParen) => break,
+                Some(_) => {}
+            }
+            terms.push(self.not()?);
+        }
+        Ok(flatten(terms, Query::And))
+    }
+
+    fn not(&mut self) -> Result<Query, QueryError> {
+        if self.peek() == Some(&Token::Not) {
+            self.next += 1;
+            return Ok(Query::Not(Box::new(self.not()?)));
+        }
+        self.primary()
+    }
+
+    fn primary(&mut self) -> Result<Query, QueryError> {
+        let position = self.position();
+        let token = self.tokens.get(self.next).map(|(_, t)| t.clone());
+        self.next += 1;
+        match token {
+            Some(Token::LParen) => {
+                let query = self.or()?;
+                match self.peek() {
+                    Some(Token::RParen) => {
+                        self.next += 1;
+                        Ok(query)
+                    }
+                    _ => Err(error(position, "unmatched '('")),
+                }
+            }
+            Some(Token::Term { field, value }) => term(position, field.as_deref(), &value),
+            Some(Token::RParen) => Err(error(position, "unexpected ')'")),
+            Some(Token::And) | Some(Token::Or) => {
+                Err(error(position, "AND and OR need a term on both sides"))
+            }
+            Some(Token::Not) => unreachable!("handled by Parser::not"),
+            None => Err(error(position, "query ends where a term was expected")),
+        }
+    }
+}
+
+fn term(position: usize, field: Option<&str>, value: &str) -> Result<Query, QueryError> {
+    let text = search::fold(value);
+    Ok(match field {
+        None => Query::Text(text),
+        Some("name") => Query::Name(text),
+        Some("genre") => Query::Genre(text),
+        Some("actor") | Some("actors") => Query::Actor(text),
+        Some(_) => {
+            let (comparison, number) = [
+                (">=", Comparison::GreaterOrEqual),
+                ("<=", Comparison::LessOrEqual),
+                (">", Comparison::Greater),
+                ("<", Comparison::Less),
+                ("=", Comparison::Equal),
+            ]
+            .iter()
+            .find_map(|(op, cmp)| value.strip_prefix(op).map(|n| (*cmp, n)))
+            .unwrap_or((Comparison::Equal, value));
+            let rating = number.parse().map_err(|_| {
+                error(
+                    position,
+                    format!("'{}' is not a rating between 0 and 10", number),
+                )
+            })?;
+            Query::Rating(comparison, rating)
+        }
+    })
+}
+
+fn flatten(mut queries: Vec<Query>, combine: fn(Vec<Query>) -> Query) -> Query {
+    match queries.len() {
+        1 => queries.remove(0),
+        _ => combine(queries),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Comparison, Query};
+    use crate::model::{movie, Genre, Movie};
+
+    fn catalog() -> Vec<Movie> {
+        vec![
+            movie(
+                "Mad Max: Fury Road",
+                Genre::Action,
+                "8.1",
+                &["Tom Hardy", "Charlize Theron"],
+            ),
+            movie(
+                "Warrior",
+                Genre::Action,
+                "8.2",
+                &["Tom Hardy", "Nick Nolte"],
+            ),
+            movie(
+                "Locke",
+                Genre::Drama,
+                "7.1",
+                &["Tom Hardy", "Olivia Colman"],
+            ),
+            movie(
+                "Edge of Tomorrow",
+                Genre::Action,
+                "7.9",
+                &["Tom Cruise", "Emily Blunt"],
+            ),
+            movie(
+                "Mononoke-hime",
+                Genre::Animation,
+                "8.4",
+                &["Yôji Matsuda", "Billy Crudup"],
+            ),
+        ]
+    }
+
+    fn names(query: &str) -> Vec<String> {
+        let query = Query::parse(query).unwrap();
+        catalog()
+            .into_iter()
+            .filter(|m| query.matches(m))
+            .map(|m| m.name)
+            .collect()
+    }
+
+    #[test]
+    fn empty_query_matches_everything() {
+        assert_eq!(Query::parse("   ").unwrap(), Query::All);
+        assert_eq!(names("").len(), 5);
+    }
+
+    #[test]
+    fn fields_phrases_comparisons_and_negation() {
+        assert_eq!(
+            names("actor:\"Tom Hardy\" genre:action rating:>=8 -genre:animation"),
+            ["Mad Max: Fury Road", "Warrior"]
+        );
+        assert_eq!(names("rating:<8 NOT drama"), ["Edge of Tomorrow"]);
+        assert_eq!(names("rating:8.4"), ["Mononoke-hime"]);
+        assert_eq!(names("\"tom c\""), ["Edge of Tomorrow"]);
+        assert_eq!(names("actor:yoji"), ["Mononoke-hime"]);
+        assert_eq!(names("name:\"edge of tomorow\""), ["Edge of Tomorrow"]);
+    }
+
+    #[test]
+    fn or_binds_looser_than_and() {
+        assert_eq!(names("locke OR warrior rating:>8"), ["Warrior", "Locke"]);
+        assert_eq!(names("(locke OR warrior) rating:>8"), ["Warrior"]);
+        assert_eq!(names("hardy AND (drama OR animation)"), ["Locke"]);
+    }
+
+    #[test]
+    fn colons_in_titles_stay_text() {
+        assert_eq!(names("max: fury"), ["Mad Max: Fury Road"]);
+        assert_eq!(names("name:max"), ["Mad Max: Fury Road"]);
+    }
+
+    #[test]
+    fn parses_into_a_tree() {
+        assert_eq!(
+            Query::parse("-genre:drama OR rating:>7").unwrap(),
+            Query::Or(vec![
+                Query::Not(Box::new(Query::Genre("drama".to_string()))),
+                Query::Rating(Comparison::Greater, "7".parse().unwrap()),
+            ])
+        );
+    }
+
+    #[test]
+    fn errors_point_at_the_problem() {
+        let err = |q: &str| Query::parse(q).unwrap_err();
+        assert_eq!(err("actor:\"Tom Hardy").position, 6);
+        assert_eq!(
+            err("rating:>=high").message,
+            "'high' is not a rating between 0 and 10"
+        );
+        assert_eq!(err("(drama").message, "unmatched '('");
+        assert_eq!(err("drama)").position, 5);
+        assert_eq!(
+            err("drama OR").message,
+            "query ends where a term was expected"
+        );
+        assert_eq!(err("genre:").message, "'genre:' needs a value");
+    }
+}
@@ -0,0 +1,182 @@
+//! The user's own scores and notes, saved by the app as part of
+//! [`crate::userdata::UserData`].
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::Rating;
+/// Notes longer than this many characters are cut.
+pub const NOTE_LIMIT: usize = 280;
+
+/// The user's score of a movie, a whole number from 1 to 10.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Debug, Serialize, Deserialize)]
+#[serde(try_from = "u8", into = "u8")]
+pub struct Score(u8);
+
+impl Score {
+    pub const ALL: [Score; 10] = [
+        Score(1),
+        Score(2),
+        Score(3),
+        Score(4),
+        Score(5),
+        Score(6),
+        Score(7),
+        Score(8),
+        Score(9),
+        Score(10),
+    ];
+
+    pub fn get(self) -> u8 {
+        self.0
+    }
+
+    /// How much higher this score is than the IMDb `rating`, in tenths.
+    pub fn gap(self, rating: Rating) -> i16 {
+        i16::from(self.0) * 10 - i16::from(rating.tenths())
+    }
+}
+
+impl fmt::Display for Score {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct InvalidScore(pub String);
+
+impl fmt::Display for InvalidScore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "score \"{}\" is not a whole number from 1 to 10", self.0)
+    }
+}
+
+impl std::error::Error for InvalidScore {}
+
+impl TryFrom<u8> for Score {
+    type Error = InvalidScore;
+
+    fn try_from(n: u8) -> Result<Self, Self::Error> {
+        match n {
+            1..=10 => Ok(Score(n)),
+            _ => Err(InvalidScore(n.to_string())),
+        }
+    }
+}
+
+impl From<Score> for u8 {
+    fn from(s: Score) -> u8 {
+        s.0
+    }
+}
+
+impl FromStr for Score {
+    type Err = InvalidScore;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.trim()
+            .parse::<u8>()
+            .ok()
+            .and_then(|n| Score::try_from(n).ok())
+            .ok_or_else(|| InvalidScore(s.to_string()))
+    }
+}
+
+/// Prints how the score compares to IMDb: `+0.9`, `-2`, `±0`.
+pub fn format_gap(gap: i16) -> String {
+    let sign = match gap {
+        0 => "±",
+        g if g > 0 => "+",
+        _ => "-",
+    };
+    let gap = gap.unsigned_abs();
+    match gap % 10 {
+        0 => format!("{}{}", sign, gap / 10),
+        d => format!("{}{}.{}", sign, gap / 10, d),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Review {
+    pub score: Score,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub note: String,
+}
+
+impl Review {
+    pub fn new(score: Score, note: &str) -> Self {
+        Review {
+            score,
+            note: note.trim().chars().take(NOTE_LIMIT).collect(),
+        }
+    }
+}
+
+/// Reviews by movie id. Reviews of movies that left the catalog are kept.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Reviews {
+    reviews: BTreeMap<String, Review>,
+}
+
+impl Reviews {
+    pub fn get(&self, id: &str) -> Option<&Review> {
+        self.reviews.get(id)
+    }
+
+    pub fn set(&mut self, id: &str, review: Review) {
+        self.reviews.insert(id.to_string(), review);
+    }
+
+    pub fn remove(&mut self, id: &str) {
+        self.reviews.remove(id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scores_are_whole_numbers_from_one_to_ten() {
+        assert_eq!("9".parse::<Score>().map(Score::get), Ok(9));
+        assert!("0".parse::<Score>().is_err());
+        assert!("11".parse::<Score>().is_err());
+        assert!("8.5".parse::<Score>().is_err());
+    }
+
+    #[test]
+    fn gap_is_measured_in_tenths() {
+        let score: Score = "9".parse().unwrap();
+        assert_eq!(score.gap("8.1".parse().unwrap()), 9);
+        assert_eq!(format_gap(9), "+0.9");
+        assert_eq!(format_gap(-20), "-2");
+        assert_eq!(format_gap(0), "±0");
+    }
+
+    #[test]
+    fn notes_are_trimmed_and_cut() {
+        let score = "7".parse().unwrap();
+        assert_eq!(Review::new(score, "  slow  ").note, "slow");
+        let long = "x".repeat(NOTE_LIMIT + 10);
+        assert_eq!(Review::new(score, &long).note.len(), NOTE_LIMIT);
+    }
+
+    #[test]
+    fn json_skips_empty_notes_and_rejects_bad_scores() {
+        let mut reviews = Reviews::default();
+        reviews.set("heat", Review::new("9".parse().unwrap(), "the bank scene"));
+        reviews.set("dune", Review::new("6".parse().unwrap(), ""));
+        let json = serde_json::to_string(&reviews).unwrap();
+        assert_eq!(
+            json,
+            r#"{"dune":{"score":6},"heat":{"score":9,"note":"the bank scene"}}"#
+        );
+        assert_eq!(serde_json::from_str::<Reviews>(&json).unwrap(), reviews);
+        assert!(serde_json::from_str::<Reviews>(r#"{"heat":{"score":11}}"#).is_err());
+    }
+}
@@ -0,0 +1,205 @@
+//! Forgiving text matching: diacritics and case are ignored and small typos are
+//! tolerated, so "yojinbo" finds "Yôjinbô" and "zoe kravits" finds "Zoë Kravitz".
+
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::model::Movie;
+
+const EXACT: u32 = 100;
+const WORD_PREFIX: u32 = 80;
+const SUBSTRING: u32 = 60;
+const FUZZY: u32 = 40;
+
+/// Lowercases `s` and strips diacritics, including the letters that have no
+/// decomposition such as `ø` or `ß`.
+pub fn fold(s: &str) -> String {
+    let mut folded = String::with_capacity(s.len());
+    for c in s.nfd().filter(|c| !is_combining_mark(*c)) {
+        match c {
+            'ø' | 'Ø' => folded.push('o'),
+            'đ' | 'Đ' => folded.push('d'),
+            'ł' | 'Ł' => folded.push('l'),
+            'ı' => folded.push('i'),
+            'æ' | 'Æ' => folded.push_str("ae"),
+            'œ' | 'Œ' => folded.push_str("oe"),
+            'ß' => folded.push_str("ss"),
+            _ => folded.extend(c.to_lowercase()),
+        }
+    }
+    folded
+}
+
+/// Optimal string alignment distance: insertions, deletions, substitutions and
+/// swaps of two neighbouring characters each cost one.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut d = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d = d.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = d;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
+/// How many typos a search word of `len` characters may contain.
+pub fn allowed_typos(len: usize) -> usize {
+    match len {
+        0..=3 => 0,
+        4..=6 => 1,
+        _ => 2,
+    }
+}
+
+/// The searchable fields of a movie, already run through [`fold`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FoldedMovie {
+    pub name: String,
+    pub genre: String,
+    pub actors: Vec<String>,
+}
+
+impl From<&Movie> for FoldedMovie {
+    fn from(movie: &Movie) -> Self {
+        FoldedMovie {
+            name: fold(&movie.name),
+            genre: fold(movie.genre.as_str()),
+            actors: movie.actors.iter().map(|a| fold(a)).collect(),
+        }
+    }
+}
+
+/// Scores how well the folded search `term` matches `text`, higher is better.
+/// `None` means no match at all.
+pub fn score(text: &str, term: &str) -> Option<u32> {
+    score_folded(&fold(text), term)
+}
+
+/// [`score`] for a `text` that is already folded.
+pub fn score_folded(text: &str, term: &str) -> Option<u32> {
+    if text == term {
+        return Some(EXACT);
+    }
+    if let Some(at) = text.find(term) {
+        let word_start = text[..at]
+            .chars()
+            .next_back()
+            .is_none_or(|c| !c.is_alphanumeric());
+        return Some(if word_start { WORD_PREFIX } else { SUBSTRING });
+    }
+
+    let (words, wanted_words) = (words(text), words(term));
+    if wanted_words.is_empty() {
+        return None;
+    }
+    let mut typos = 0;
+    for wanted in wanted_words {
+        let allowed = allowed_typos(wanted.chars().count());
+        typos += words
+            .iter()
+            .map(|w| match w.starts_with(wanted) {
+                true => 0,
+                false => edit_distance(wanted, w),
+            })
+            .filter(|&d| d <= allowed)
+            .min()?;
+    }
+    Some(FUZZY.saturating_sub(10 * typos as u32).max(1))
+}
+
+/// Splits folded text into the words that [`score`] compares typos against.
+pub fn words(s: &str) -> Vec<&str> {
+    s.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect()
+}
+
+/// Best score of `term` against the name, the actors and the genre of `movie`.
+/// A hit in the name counts more than one in the cast, which counts more than the genre.
+pub fn movie_score(movie: &FoldedMovie, term: &str) -> Option<u32> {
+    let name = score_folded(&movie.name, term).map(|s| 3 * s);
+    let actor = actor_score(movie, term).map(|s| 2 * s);
+    let genre = score_folded(&movie.genre, term);
+    name.max(actor).max(genre)
+}
+
+pub fn actor_score(movie: &FoldedMovie, term: &str) -> Option<u32> {
+    movie
+        .actors
+        .iter()
+        .filter_map(|a| score_folded(a, term))
+        .max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{movie, Genre};
+    use crate::query::Query;
+
+    #[test]
+    fn folding_strips_diacritics() {
+        assert_eq!(fold("Yôjinbô"), "yojinbo");
+        assert_eq!(fold("Toshirô Mifune"), "toshiro mifune");
+        assert_eq!(fold("Bãhubali"), "bahubali");
+        assert_eq!(fold("Zoë Kravitz"), "zoe kravitz");
+        assert_eq!(fold("Søren Straße"), "soren strasse");
+    }
+
+    #[test]
+    fn edit_distance_counts_swaps_once() {
+        assert_eq!(edit_distance("kravitz", "kravitz"), 0);
+        assert_eq!(edit_distance("kravits", "kravitz"), 1);
+        assert_eq!(edit_distance("kravtiz", "kravitz"), 1);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("sitting", "kitten"), 3);
+    }
+
+    #[test]
+    fn scores_prefer_exact_over_fuzzy() {
+        assert_eq!(score("Yôjinbô", "yojinbo"), Some(EXACT));
+        assert_eq!(score("Zoë Kravitz", "kra"), Some(WORD_PREFIX));
+        assert_eq!(score("Zoë Kravitz", "vitz"), Some(SUBSTRING));
+        assert_eq!(score("Zoë Kravitz", "zoe kravits"), Some(FUZZY - 10));
+        assert_eq!(score("Zoë Kravitz", "zoey kravits"), Some(FUZZY - 20));
+        assert_eq!(score("Heat", "hat"), None);
+        assert_eq!(score("Zoë Kravitz", "kevin"), None);
+    }
+
+    #[test]
+    fn names_outscore_actors() {
+        let query = Query::parse("kravits").unwrap();
+        let score = |m: Movie| query.score(&m);
+        let title = score(movie("Kravitz", Genre::Action, "8", &[]));
+        let actor = score(movie(
+            "Mad Max: Fury Road",
+            Genre::Action,
+            "8",
+            &["Tom Hardy", "Zoë Kravitz"],
+        ));
+        assert!(title > actor && actor.is_some());
+        assert_eq!(
+            actor,
+            score(movie("The Batman", Genre::Action, "8", &["Zoë Kravitz"]))
+        );
+        assert_eq!(
+            score(movie("Heat", Genre::Action, "8", &["Al Pacino"])),
+            None
+        );
+    }
+}
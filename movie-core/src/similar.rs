@@ -0,0 +1,182 @@
+//! "Similar movies": other catalog movies ranked by shared actors, genre and
+//! how close their rating is, each with the reason it was picked.
+
+use std::cmp::Reverse;
+
+use crate::model::{Genre, Movie};
+
+/// Points a candidate earns for each thing it has in common with the movie.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Weights {
+    /// Per actor playing in both movies.
+    pub shared_actor: u32,
+    pub same_genre: u32,
+    /// For an equal rating, falling linearly to nothing `rating_window` tenths apart.
+    pub rating: u32,
+    pub rating_window: u32,
+}
+
+impl Default for Weights {
+    fn default() -> Self {
+        Weights {
+            shared_actor: 30,
+            same_genre: 20,
+            rating: 10,
+            rating_window: 20,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recommendation {
+    /// Position of the recommended movie in the catalog.
+    pub movie: usize,
+    pub score: u32,
+    /// The shared actors, spelled as in the recommended movie.
+    pub shared_actors: Vec<String>,
+    /// The shared genre, if any.
+    pub genre: Option<Genre>,
+}
+
+impl Recommendation {
+    /// Why the movie was picked, e.g. "shares Tom Hardy and Gary Oldman · also Drama".
+    pub fn reason(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some((last, rest)) = self.shared_actors.split_last() {
+            parts.push(match rest.is_empty() {
+                true => format!("shares {}", last),
+                false => format!("shares {} and {}", rest.join(", "), last),
+            });
+        }
+        if let Some(genre) = self.genre {
+            parts.push(format!("also {}", genre));
+        }
+        parts.join(" · ")
+    }
+}
+
+/// The `n` movies of `movies` most similar to `movies[of]`, best first. Only
+/// movies sharing an actor or the genre are considered, and ties go to the one
+/// earlier in the catalog, so the result only depends on the catalog.
+/// `actor_ids` are the [`crate::actor::actor_ids`] of `movies`.
+pub fn similar<M: AsRef<Movie>, A: AsRef<[String]>>(
+    movies: &[M],
+    actor_ids: &[A],
+    of: usize,
+    n: usize,
+    weights: &Weights,
+) -> Vec<Recommendation> {
+    let movie = movies[of].as_ref();
+    let cast = actor_ids[of].as_ref();
+    let mut recommendations: Vec<Recommendation> = movies
+        .iter()
+        .enumerate()
+        .filter(|&(i, other)| i != of && other.as_ref() != movie)
+        .filter_map(|(i, other)| {
+            let other = other.as_ref();
+            let shared_actors: Vec<String> = (other.actors.iter())
+                .zip(actor_ids[i].as_ref())
+                .filter(|(_, id)| cast.contains(id))
+                .map(|(a, _)| a.clone())
+                .collect();
+            let genre = (other.genre == movie.genre).then_some(movie.genre);
+            if shared_actors.is_empty() && genre.is_none() {
+                return None;
+            }
+            let gap = u32::from(movie.rating.tenths().abs_diff(other.rating.tenths()));
+            let closeness = weights.rating_window.saturating_sub(gap);
+            let score = weights.shared_actor * shared_actors.len() as u32
+                + genre.map_or(0, |_| weights.same_genre)
+                + weights.rating * closeness / weights.rating_window.max(1);
+            Some(Recommendation {
+                movie: i,
+                score,
+                shared_actors,
+                genre,
+            })
+        })
+        .collect();
+    recommendations.sort_by_key(|r| (Reverse(r.score), r.movie));
+    recommendations.truncate(n);
+    recommendations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actor::actor_ids;
+    use crate::model::movie;
+
+    fn movies() -> Vec<Movie> {
+        vec![
+            movie(
+                "The Dark Knight Rises",
+                Genre::Action,
+                "8.4",
+                &["Christian Bale", "Tom Hardy", "Gary Oldman"],
+            ),
+            movie(
+                "Tinker Tailor Soldier Spy",
+                Genre::Drama,
+                "7.1",
+                &["Gary Oldman", "Colin Firth", "Tom Hardy"],
+            ),
+            movie(
+                "Mad Max: Fury Road",
+                Genre::Action,
+                "8.1",
+                &["Tom Hardy", "Charlize Theron"],
+            ),
+            movie(
+                "Heat",
+                Genre::Action,
+                "8.3",
+                &["Al Pacino", "Robert De Niro"],
+            ),
+            movie("Amélie", Genre::Comedy, "8.3", &["Audrey Tautou"]),
+            movie("Die Hard", Genre::Action, "8.2", &["Bruce Willis"]),
+        ]
+    }
+
+    #[test]
+    fn shared_actors_outweigh_genre() {
+        let movies = movies();
+        let picks = similar(&movies, &actor_ids(&movies), 0, 10, &Weights::default());
+        let order: Vec<usize> = picks.iter().map(|r| r.movie).collect();
+        // Amélie has nothing in common but its rating, so it is left out.
+        assert_eq!(order, [1, 2, 3, 5]);
+        assert_eq!(picks[0].score, 2 * 30 + 10 * 7 / 20);
+        assert_eq!(picks[1].score, 30 + 20 + 10 * 17 / 20);
+        assert_eq!(picks[0].reason(), "shares Gary Oldman and Tom Hardy");
+        assert_eq!(picks[1].reason(), "shares Tom Hardy · also Action");
+        assert_eq!(picks[2].reason(), "also Action");
+    }
+
+    #[test]
+    fn weights_change_the_ranking() {
+        let weights = Weights {
+            shared_actor: 5,
+            same_genre: 50,
+            ..Weights::default()
+        };
+        let movies = movies();
+        let order: Vec<usize> = similar(&movies, &actor_ids(&movies), 0, 3, &weights)
+            .iter()
+            .map(|r| r.movie)
+            .collect();
+        assert_eq!(order, [2, 3, 5]);
+    }
+
+    #[test]
+    fn ties_keep_catalog_order() {
+        let mut movies = movies();
+        movies.push(movies[3].clone());
+        let picks = similar(&movies, &actor_ids(&movies), 0, 10, &Weights::default());
+        let heats: Vec<usize> = picks
+            .iter()
+            .filter(|r| movies[r.movie].name == "Heat")
+            .map(|r| r.movie)
+            .collect();
+        assert_eq!(heats, [3, 6]);
+    }
+}
@@ -0,0 +1,87 @@
+//! Stable ids for the catalog, used in urls such as `/movie/the-dark-knight`.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::model::Movie;
+use crate::search::fold;
+
+/// `name` folded to lowercase ASCII letters and digits joined by dashes,
+/// e.g. "Yôjinbô: The Bodyguard" becomes "yojinbo-the-bodyguard".
+pub fn slug(name: &str) -> String {
+    let folded = fold(name);
+    let words: Vec<&str> = folded
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect();
+    words.join("-")
+}
+
+/// The id of every movie, in the order of `movies`. Movies sharing a name are
+/// told apart by a number in catalog order: the first "Dune" is `dune`, the
+/// second `dune-2`, so ids only change when a same-named movie is added above.
+pub fn movie_ids<M: AsRef<Movie>>(movies: &[M]) -> Vec<String> {
+    let slugs: Vec<String> = movies
+        .iter()
+        .map(|m| match slug(&m.as_ref().name) {
+            s if s.is_empty() => "movie".to_string(),
+            s => s,
+        })
+        .collect();
+    let mut taken: HashSet<String> = slugs.iter().cloned().collect();
+    let mut last_number: HashMap<String, usize> = HashMap::new();
+    slugs
+        .into_iter()
+        .map(|base| {
+            let n = last_number.entry(base.clone()).or_insert(0);
+            *n += 1;
+            if *n == 1 {
+                return base;
+            }
+            // A movie really called "Dune 2" already owns `dune-2`.
+            while taken.contains(&format!("{}-{}", base, n)) {
+                *n += 1;
+            }
+            let id = format!("{}-{}", base, n);
+            taken.insert(id.clone());
+            id
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::{self, IMDB_DATA};
+    use crate::model::{movie, Genre};
+
+    #[test]
+    fn slugs_are_folded_words() {
+        assert_eq!(slug("Yôjinbô"), "yojinbo");
+        assert_eq!(slug("Mad Max: Fury Road"), "mad-max-fury-road");
+        assert_eq!(slug("  Léon: The Professional "), "leon-the-professional");
+        assert_eq!(slug("8½"), "8");
+        assert_eq!(slug("???"), "");
+        assert_eq!(
+            movie_ids(&[movie("???", Genre::Action, "8", &[])]),
+            ["movie"]
+        );
+    }
+
+    #[test]
+    fn duplicate_names_get_numbered() {
+        let movies = [
+            movie("Dune", Genre::Action, "8", &[]),
+            movie("Dune 2", Genre::Action, "8", &[]),
+            movie("Dune", Genre::Action, "8", &[]),
+            movie("Dune", Genre::Action, "8", &[]),
+        ];
+        assert_eq!(movie_ids(&movies), ["dune", "dune-2", "dune-3", "dune-4"]);
+    }
+
+    #[test]
+    fn catalog_ids_are_unique() {
+        let movies: Vec<Movie> = catalog::parse(IMDB_DATA).map(Result::unwrap).collect();
+        let ids = movie_ids(&movies);
+        assert_eq!(ids.iter().collect::<HashSet<_>>().len(), movies.len());
+    }
+}
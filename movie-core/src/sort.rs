@@ -0,0 +1,185 @@
+use std::cmp::Ordering;
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::Movie;
+use crate::review::Score;
+
+#[allow(clippy::enum_variant_names)]
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum Sort {
+    #[serde(rename = "name")]
+    ByName,
+    #[serde(rename = "genre")]
+    ByGenre,
+    #[serde(rename = "rating")]
+    ByRating,
+    /// The user's own score.
+    #[serde(rename = "mine")]
+    ByMyRating,
+    /// How much higher the user scored a movie than IMDb.
+    #[serde(rename = "gap")]
+    ByRatingGap,
+}
+
+/// A movie together with the score the user gave it, for the personal sorts.
+#[derive(Clone, Copy, Debug)]
+pub struct Rated<'a> {
+    pub movie: &'a Movie,
+    pub mine: Option<Score>,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum Direction {
+    #[serde(rename = "asc")]
+    Ascending,
+    #[serde(rename = "desc")]
+    Descending,
+}
+
+impl Direction {
+    pub fn reversed(self) -> Self {
+        match self {
+            Direction::Ascending => Direction::Descending,
+            Direction::Descending => Direction::Ascending,
+        }
+    }
+
+    /// Turns an ascending comparison into one in this direction.
+    pub fn order(self, ascending: Ordering) -> Ordering {
+        match self {
+            Direction::Ascending => ascending,
+            Direction::Descending => ascending.reverse(),
+        }
+    }
+}
+
+impl Sort {
+    /// The direction a column is sorted in when it is picked: names and genres
+    /// from A to Z, ratings and gaps from highest to lowest.
+    pub fn default_direction(&self) -> Direction {
+        match self {
+            Sort::ByName | Sort::ByGenre => Direction::Ascending,
+            Sort::ByRating | Sort::ByMyRating | Sort::ByRatingGap => Direction::Descending,
+        }
+    }
+
+    /// Whether the sort needs the user's scores, see [`Sort::compare_rated`].
+    pub fn is_personal(&self) -> bool {
+        matches!(self, Sort::ByMyRating | Sort::ByRatingGap)
+    }
+
+    /// Compares in ascending order of the sort key. Without the user's scores
+    /// every movie is equal for the personal sorts.
+    pub fn compare(&self, a: &Movie, b: &Movie) -> Ordering {
+        match self {
+            Sort::ByName => a.name.cmp(&b.name),
+            Sort::ByGenre => a.genre.cmp(&b.genre),
+            Sort::ByRating => a.rating.cmp(&b.rating),
+            Sort::ByMyRating | Sort::ByRatingGap => Ordering::Equal,
+        }
+    }
+
+    /// Compares in `direction`, using the user's scores for the personal sorts.
+    /// Movies the user hasn't scored come last in either direction.
+    pub fn compare_rated(&self, direction: Direction, a: Rated, b: Rated) -> Ordering {
+        if !self.is_personal() {
+            return direction.order(self.compare(a.movie, b.movie));
+        }
+        let key = |r: Rated| match self {
+            Sort::ByMyRating => r.mine.map(|s| i16::from(s.get())),
+            Sort::ByRatingGap => r.mine.map(|s| s.gap(r.movie.rating)),
+            Sort::ByName | Sort::ByGenre | Sort::ByRating => None,
+        };
+        match (key(a), key(b)) {
+            (Some(x), Some(y)) => direction.order(x.cmp(&y)),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    }
+
+    /// Stable sort, so movies that compare equal keep their previous order.
+    pub fn apply<M: AsRef<Movie>>(&self, direction: Direction, movies: &mut [M]) {
+        movies.sort_by(|a, b| direction.order(self.compare(a.as_ref(), b.as_ref())));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction, Rated, Sort};
+    use crate::model::{movie, Genre, Movie};
+    use crate::review::Score;
+
+    fn names(movies: &[Movie]) -> Vec<&str> {
+        movies.iter().map(|m| m.name.as_str()).collect()
+    }
+
+    #[test]
+    fn ratings_sort_numerically() {
+        let mut movies = vec![
+            movie("a", Genre::Drama, "8.1", &[]),
+            movie("b", Genre::Drama, "10", &[]),
+            movie("c", Genre::Drama, "2", &[]),
+            movie("d", Genre::Drama, "8", &[]),
+        ];
+        Sort::ByRating.apply(Sort::ByRating.default_direction(), &mut movies);
+        assert_eq!(names(&movies), ["b", "a", "d", "c"]);
+    }
+
+    #[test]
+    fn genre_sort_is_stable_and_alphabetical() {
+        let mut movies = vec![
+            movie("a", Genre::Western, "7", &[]),
+            movie("b", Genre::FilmNoir, "7", &[]),
+            movie("c", Genre::Action, "7", &[]),
+            movie("d", Genre::FilmNoir, "7", &[]),
+        ];
+        Sort::ByGenre.apply(Direction::Ascending, &mut movies);
+        assert_eq!(names(&movies), ["c", "b", "d", "a"]);
+        Sort::ByGenre.apply(Direction::Descending, &mut movies);
+        assert_eq!(names(&movies), ["a", "b", "d", "c"]);
+    }
+
+    #[test]
+    fn personal_sorts_put_unscored_movies_last() {
+        let movies = [
+            movie("a", Genre::Drama, "8.1", &[]),
+            movie("b", Genre::Drama, "6", &[]),
+            movie("c", Genre::Drama, "9", &[]),
+            movie("d", Genre::Drama, "7", &[]),
+        ];
+        let mine: [Option<Score>; 4] =
+            [Some(9), None, Some(8), Some(9)].map(|s| s.map(|s: u8| Score::try_from(s).unwrap()));
+        let sorted = |sort: Sort, direction: Direction| {
+            let mut order: Vec<usize> = (0..movies.len()).collect();
+            order.sort_by(|&x, &y| {
+                let rated = |i: usize| Rated {
+                    movie: &movies[i],
+                    mine: mine[i],
+                };
+                sort.compare_rated(direction, rated(x), rated(y))
+            });
+            order
+                .iter()
+                .map(|&i| movies[i].name.as_str())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            sorted(Sort::ByMyRating, Direction::Descending),
+            ["a", "d", "c", "b"]
+        );
+        assert_eq!(
+            sorted(Sort::ByMyRating, Direction::Ascending),
+            ["c", "a", "d", "b"]
+        );
+        assert_eq!(
+            sorted(Sort::ByRatingGap, Direction::Descending),
+            ["d", "a", "c", "b"]
+        );
+        assert_eq!(
+            sorted(Sort::ByRatingGap, Direction::Ascending),
+            ["c", "a", "d", "b"]
+        );
+    }
+}
@@ -0,0 +1,124 @@
+//! YouTube trailer urls, turned into the privacy-enhanced embed played in the app.
+
+/// Hosts serving the video as `/watch?v=<id>`, `/shorts/<id>` or `/embed/<id>`.
+const HOSTS: [&str; 5] = [
+    "youtube.com",
+    "www.youtube.com",
+    "m.youtube.com",
+    "youtube-nocookie.com",
+    "www.youtube-nocookie.com",
+];
+
+/// The id of the YouTube video `url` points to, e.g. `8g18jFHCLXk` for
+/// `https://www.youtube.com/watch?v=8g18jFHCLXk`, `https://youtu.be/8g18jFHCLXk`
+/// or `https://youtube.com/shorts/8g18jFHCLXk`. `None` for anything else.
+pub fn video_id(url: &str) -> Option<&str> {
+    let url = url.trim();
+    let rest = (url.strip_prefix("https://"))
+        .or_else(|| url.strip_prefix("http://"))
+        .unwrap_or(url);
+    let rest = rest.split('#').next().unwrap_or_default();
+    let (host, path) = rest.split_once('/')?;
+    let (path, query) = path.split_once('?').unwrap_or((path, ""));
+    let id = match host.to_ascii_lowercase().as_str() {
+        "youtu.be" => path,
+        host if HOSTS.contains(&host) => match path.trim_end_matches('/') {
+            "watch" => query.split('&').find_map(|pair| pair.strip_prefix("v="))?,
+            path => match path.split_once('/') {
+                Some(("shorts" | "embed", id)) => id,
+                _ => return None,
+            },
+        },
+        _ => return None,
+    };
+    let id = id.trim_end_matches('/');
+    is_video_id(id).then_some(id)
+}
+
+/// Video ids are 11 letters, digits, `-` or `_`.
+fn is_video_id(id: &str) -> bool {
+    id.len() == 11
+        && id
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+}
+
+/// The player of video `id` on youtube-nocookie.com, which sets no cookies
+/// until the video is played.
+pub fn embed_url(id: &str) -> String {
+    format!("https://www.youtube-nocookie.com/embed/{}?autoplay=1", id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ID: Option<&str> = Some("8g18jFHCLXk");
+
+    #[test]
+    fn watch_urls() {
+        assert_eq!(video_id("https://www.youtube.com/watch?v=8g18jFHCLXk"), ID);
+        assert_eq!(video_id("http://youtube.com/watch?v=8g18jFHCLXk"), ID);
+        assert_eq!(video_id("https://m.youtube.com/watch?v=8g18jFHCLXk"), ID);
+        assert_eq!(video_id("www.youtube.com/watch?v=8g18jFHCLXk"), ID);
+        assert_eq!(
+            video_id("https://www.youtube.com/watch?feature=share&v=8g18jFHCLXk&t=42s"),
+            ID
+        );
+        assert_eq!(
+            video_id("https://www.youtube.com/watch?v=8g18jFHCLXk#comments"),
+            ID
+        );
+    }
+
+    #[test]
+    fn short_links() {
+        assert_eq!(video_id("https://youtu.be/8g18jFHCLXk"), ID);
+        assert_eq!(video_id("https://youtu.be/8g18jFHCLXk?t=10"), ID);
+        assert_eq!(video_id(" https://YOUTU.BE/8g18jFHCLXk/ "), ID);
+    }
+
+    #[test]
+    fn shorts_and_embeds() {
+        assert_eq!(video_id("https://www.youtube.com/shorts/8g18jFHCLXk"), ID);
+        assert_eq!(
+            video_id("https://youtube.com/shorts/8g18jFHCLXk?feature=share"),
+            ID
+        );
+        assert_eq!(video_id("https://www.youtube.com/embed/8g18jFHCLXk"), ID);
+        assert_eq!(
+            video_id("https://www.youtube-nocookie.com/embed/8g18jFHCLXk"),
+            ID
+        );
+    }
+
+    #[test]
+    fn other_urls_have_no_id() {
+        for url in [
+            "",
+            "https://www.youtube.com/",
+            "https://www.youtube.com/watch",
+            "https://www.youtube.com/watch?list=PL123",
+            "https://www.youtube.com/watch?v=short",
+            "https://www.youtube.com/watch?v=8g18jFHCLXk!",
+            "https://www.youtube.com/channel/8g18jFHCLXk",
+            "https://www.youtube.com/shorts/8g18jFHCLXk/extra",
+            "https://youtu.be/",
+            "https://vimeo.com/watch?v=8g18jFHCLXk",
+            "https://youtube.com.example.org/watch?v=8g18jFHCLXk",
+        ] {
+            assert_eq!(video_id(url), None, "{}", url);
+        }
+    }
+
+    #[test]
+    fn every_award_trailer_plays_in_the_app() {
+        for award in crate::award::AWARDS {
+            assert!(video_id(award.trailer).is_some(), "{}", award.trailer);
+        }
+        assert_eq!(
+            embed_url("8g18jFHCLXk"),
+            "https://www.youtube-nocookie.com/embed/8g18jFHCLXk?autoplay=1"
+        );
+    }
+}
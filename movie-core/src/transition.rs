@@ -0,0 +1,162 @@
+//! The re-sort transition of the library: changing the sort fades the rows
+//! out, re-sorts them while they are hidden and fades them back in. Each step
+//! waits for the CSS animation of the previous one to end.
+
+use crate::sort::{Direction, Sort};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum ListState {
+    Idle,
+    FadingOut(Sort, Direction),
+    Resorting(Sort, Direction),
+    FadingIn,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum ListEvent {
+    /// A sort was picked while the list is sorted by `shown`. `rows` tells
+    /// whether any row is visible, i.e. whether there is a fade to wait for.
+    Picked {
+        sort: Sort,
+        direction: Direction,
+        shown: (Sort, Direction),
+        rows: bool,
+    },
+    /// An animation ended, either the list's own or, when `own` is false, one
+    /// of a row bubbling up to the list.
+    AnimationEnd { own: bool },
+    /// The rows were put in the order of [`ListState::Resorting`].
+    Resorted { rows: bool },
+}
+
+impl ListState {
+    pub fn next(self, event: ListEvent) -> Self {
+        use ListState::*;
+        match event {
+            ListEvent::Picked {
+                sort,
+                direction,
+                shown,
+                rows,
+            } => match self {
+                Idle if shown == (sort, direction) => Idle,
+                // The pending re-sort picks up the latest sort.
+                Resorting(..) => Resorting(sort, direction),
+                _ if rows => FadingOut(sort, direction),
+                _ => Resorting(sort, direction),
+            },
+            ListEvent::AnimationEnd { own: false } => self,
+            ListEvent::AnimationEnd { own: true } => match self {
+                FadingOut(sort, direction) => Resorting(sort, direction),
+                FadingIn => Idle,
+                state => state,
+            },
+            ListEvent::Resorted { rows } => match self {
+                Resorting(..) if rows => FadingIn,
+                Resorting(..) => Idle,
+                state => state,
+            },
+        }
+    }
+
+    /// Whether the state is waiting for the rows to be re-sorted.
+    pub fn is_resorting(self) -> bool {
+        matches!(self, ListState::Resorting(..))
+    }
+
+    /// The sort the list shows once the running transition is over, `shown` if none is running.
+    pub fn target(self, shown: (Sort, Direction)) -> (Sort, Direction) {
+        match self {
+            ListState::FadingOut(sort, direction) | ListState::Resorting(sort, direction) => {
+                (sort, direction)
+            }
+            ListState::Idle | ListState::FadingIn => shown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHOWN: (Sort, Direction) = (Sort::ByGenre, Direction::Ascending);
+
+    fn picked(sort: Sort, direction: Direction, rows: bool) -> ListEvent {
+        ListEvent::Picked {
+            sort,
+            direction,
+            shown: SHOWN,
+            rows,
+        }
+    }
+
+    const OWN_END: ListEvent = ListEvent::AnimationEnd { own: true };
+
+    #[test]
+    fn a_sort_fades_out_resorts_and_fades_in() {
+        let state = ListState::Idle.next(picked(Sort::ByRating, Direction::Descending, true));
+        assert_eq!(
+            state,
+            ListState::FadingOut(Sort::ByRating, Direction::Descending)
+        );
+        let state = state.next(OWN_END);
+        assert_eq!(
+            state,
+            ListState::Resorting(Sort::ByRating, Direction::Descending)
+        );
+        assert!(state.is_resorting());
+        let state = state.next(ListEvent::Resorted { rows: true });
+        assert_eq!(state, ListState::FadingIn);
+        assert_eq!(state.next(OWN_END), ListState::Idle);
+    }
+
+    #[test]
+    fn the_shown_sort_or_no_rows_skip_the_fades() {
+        let same = picked(SHOWN.0, SHOWN.1, true);
+        assert_eq!(ListState::Idle.next(same), ListState::Idle);
+        let state = ListState::Idle.next(picked(Sort::ByName, Direction::Ascending, false));
+        assert_eq!(
+            state,
+            ListState::Resorting(Sort::ByName, Direction::Ascending)
+        );
+        assert_eq!(
+            state.next(ListEvent::Resorted { rows: false }),
+            ListState::Idle
+        );
+    }
+
+    #[test]
+    fn a_sort_picked_mid_fade_wins() {
+        let state = ListState::Idle.next(picked(Sort::ByRating, Direction::Descending, true));
+        let state = state.next(picked(Sort::ByName, Direction::Ascending, true));
+        assert_eq!(
+            state,
+            ListState::FadingOut(Sort::ByName, Direction::Ascending)
+        );
+        assert_eq!(state.target(SHOWN), (Sort::ByName, Direction::Ascending));
+
+        // Picked while fading back in, even the sort just applied fades out again.
+        let state = ListState::FadingIn.next(picked(SHOWN.0, SHOWN.1, true));
+        assert_eq!(state, ListState::FadingOut(SHOWN.0, SHOWN.1));
+
+        let state = ListState::Resorting(Sort::ByRating, Direction::Descending).next(picked(
+            Sort::ByName,
+            Direction::Descending,
+            true,
+        ));
+        assert_eq!(
+            state,
+            ListState::Resorting(Sort::ByName, Direction::Descending)
+        );
+    }
+
+    #[test]
+    fn animations_of_rows_are_ignored() {
+        let bubbled = ListEvent::AnimationEnd { own: false };
+        let fading = ListState::FadingOut(Sort::ByRating, Direction::Descending);
+        assert_eq!(fading.next(bubbled), fading);
+        assert_eq!(ListState::FadingIn.next(bubbled), ListState::FadingIn);
+        assert_eq!(ListState::Idle.next(OWN_END), ListState::Idle);
+        assert_eq!(ListState::Idle.target(SHOWN), SHOWN);
+    }
+}
@@ -0,0 +1,323 @@
+//! Everything the user keeps in the browser, saved as one versioned document
+//! under [`STORAGE_KEY`]:
+//!
+//! ```text
+//! {"version":2,"watchlist":[...],"reviews":{...},"lists":[...]}
+//! ```
+//!
+//! Saves of an older version are brought up to date by running the
+//! [`MIGRATIONS`] from their version on, so a change of format never loses
+//! data. Version 1 kept the watchlist, the reviews and the lists under
+//! separate keys, each with its own `version` field.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::lists::Lists;
+use crate::review::Reviews;
+use crate::watchlist::Watchlist;
+
+pub const SCHEMA_VERSION: u32 = 2;
+
+pub const STORAGE_KEY: &str = "movie-library.data";
+
+/// The keys of version 1, by the name of the data saved under them.
+pub const V1_KEYS: [(&str, &str); 3] = [
+    ("watchlist", "movie-library.watchlist"),
+    ("reviews", "movie-library.reviews"),
+    ("lists", "movie-library.lists"),
+];
+
+type Migration = fn(Value) -> Result<Value, String>;
+
+/// `MIGRATIONS[i]` turns a document of version `i + 1` into one of version `i + 2`.
+pub const MIGRATIONS: [Migration; SCHEMA_VERSION as usize - 1] = [v1_to_v2];
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct UserData {
+    #[serde(default)]
+    pub watchlist: Watchlist,
+    #[serde(default)]
+    pub reviews: Reviews,
+    #[serde(default)]
+    pub lists: Lists,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaError {
+    Json(String),
+    /// Saved by a newer version of the app, which this one must not overwrite.
+    TooNew(u32),
+    Migration {
+        from: u32,
+        message: String,
+    },
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchemaError::Json(e) => write!(f, "saved data is not valid: {}", e),
+            SchemaError::TooNew(v) => write!(
+                f,
+                "saved data has version {}, this app only knows up to {}",
+                v, SCHEMA_VERSION
+            ),
+            SchemaError::Migration { from, message } => {
+                write!(
+                    f,
+                    "could not migrate saved data from version {}: {}",
+                    from, message
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for SchemaError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Loaded {
+    pub data: UserData,
+    /// The version that was saved. Older than [`SCHEMA_VERSION`] if the data was migrated.
+    pub version: u32,
+    /// The version 1 keys that couldn't be read, with the reason. They were left
+    /// out of `data` so the others could still be migrated, and should be kept aside.
+    pub unreadable: Vec<(&'static str, SchemaError)>,
+}
+
+impl UserData {
+    pub fn to_json(&self) -> String {
+        #[derive(Serialize)]
+        struct Document<'a> {
+            version: u32,
+            #[serde(flatten)]
+            data: &'a UserData,
+        }
+        serde_json::to_string(&Document {
+            version: SCHEMA_VERSION,
+            data: self,
+        })
+        .expect("user data always serializes")
+    }
+
+    /// Reads the saved data of any known version, `read` returning what is
+    /// stored under a key. `None` if nothing was saved yet.
+    pub fn load(read: impl Fn(&str) -> Option<String>) -> Result<Option<Loaded>, SchemaError> {
+        let (document, unreadable) = match read(STORAGE_KEY) {
+            Some(json) => {
+                let document =
+                    serde_json::from_str(&json).map_err(|e| SchemaError::Json(e.to_string()))?;
+                (document, Vec::new())
+            }
+            None => match v1_document(&read) {
+                Some(v1) => v1,
+                None => return Ok(None),
+            },
+        };
+        let (data, version) = read_document(document)?;
+        Ok(Some(Loaded {
+            data,
+            version,
+            unreadable,
+        }))
+    }
+}
+
+/// Runs the migrations a document needs, returning it at [`SCHEMA_VERSION`]
+/// together with the version it had.
+pub fn migrate(mut document: Value) -> Result<(Value, u32), SchemaError> {
+    let version = document["version"]
+        .as_u64()
+        .and_then(|v| u32::try_from(v).ok())
+        .filter(|&v| v > 0)
+        .ok_or_else(|| SchemaError::Json("missing or invalid \"version\"".to_string()))?;
+    if version > SCHEMA_VERSION {
+        return Err(SchemaError::TooNew(version));
+    }
+    for from in version..SCHEMA_VERSION {
+        document = MIGRATIONS[from as usize - 1](document)
+            .map_err(|message| SchemaError::Migration { from, message })?;
+    }
+    Ok((document, version))
+}
+
+fn read_document(document: Value) -> Result<(UserData, u32), SchemaError> {
+    let (document, version) = migrate(document)?;
+    let data = serde_json::from_value(document).map_err(|e| SchemaError::Json(e.to_string()))?;
+    Ok((data, version))
+}
+
+/// Gathers the separate keys of version 1 into one document:
+/// `{"version":1,"watchlist":<blob>,"reviews":<blob>,"lists":<blob>}`.
+/// Each key is tried on its own first, and the ones that can't be read are
+/// left out and returned, so one broken key doesn't lose the others.
+fn v1_document(
+    read: impl Fn(&str) -> Option<String>,
+) -> Option<(Value, Vec<(&'static str, SchemaError)>)> {
+    let mut document = json!({ "version": 1 });
+    let mut unreadable = Vec::new();
+    let mut found = false;
+    for (name, key) in V1_KEYS {
+        let Some(json) = read(key) else {
+            continue;
+        };
+        found = true;
+        let blob = serde_json::from_str(&json)
+            .map_err(|e| SchemaError::Json(e.to_string()))
+            .and_then(|blob: Value| {
+                read_document(json!({ "version": 1, name: blob.clone() }))?;
+                Ok(blob)
+            });
+        match blob {
+            Ok(blob) => document[name] = blob,
+            Err(e) => unreadable.push((key, e)),
+        }
+    }
+    found.then_some((document, unreadable))
+}
+
+fn v1_to_v2(document: Value) -> Result<Value, String> {
+    let part = |name: &str, field: &str, empty: Value| match &document[name] {
+        Value::Null => Ok(empty),
+        blob if blob["version"] == 1 => Ok(blob[field].clone()),
+        blob => Err(format!(
+            "{} has the unknown version {}",
+            name, blob["version"]
+        )),
+    };
+    Ok(json!({
+        "version": 2,
+        "watchlist": part("watchlist", "entries", json!([]))?,
+        "reviews": part("reviews", "reviews", json!({}))?,
+        "lists": part("lists", "lists", json!([]))?,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    macro_rules! fixture {
+        ($file:expr) => {
+            include_str!(concat!("../fixtures/storage/", $file)).trim()
+        };
+    }
+
+    fn storage(items: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let items: HashMap<String, String> = items
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |key| items.get(key).cloned()
+    }
+
+    fn load(items: &[(&str, &str)]) -> Result<Option<Loaded>, SchemaError> {
+        UserData::load(storage(items))
+    }
+
+    #[test]
+    fn nothing_saved_loads_nothing() {
+        assert_eq!(load(&[]), Ok(None));
+    }
+
+    #[test]
+    fn version_1_keys_are_migrated() {
+        let loaded = load(&[
+            ("movie-library.watchlist", fixture!("v1_watchlist.json")),
+            ("movie-library.reviews", fixture!("v1_reviews.json")),
+            ("movie-library.lists", fixture!("v1_lists.json")),
+        ])
+        .unwrap()
+        .unwrap();
+        assert_eq!(loaded.version, 1);
+        let data = loaded.data;
+        let watched: Vec<&str> = data
+            .watchlist
+            .entries()
+            .iter()
+            .map(|e| e.id.as_str())
+            .collect();
+        assert_eq!(watched, ["heat", "gone-movie"]);
+        assert_eq!(data.reviews.get("heat").unwrap().note, "the bank scene");
+        assert_eq!(data.reviews.get("dune-2").unwrap().score.get(), 6);
+        assert_eq!(data.lists.lists()[0].name, "Kurosawa marathon");
+        assert_eq!(data.lists.lists()[0].movies.len(), 2);
+    }
+
+    #[test]
+    fn missing_version_1_keys_become_empty() {
+        let data = load(&[("movie-library.reviews", fixture!("v1_reviews.json"))])
+            .unwrap()
+            .unwrap()
+            .data;
+        assert!(data.watchlist.entries().is_empty());
+        assert!(data.lists.lists().is_empty());
+        assert!(data.reviews.get("heat").is_some());
+    }
+
+    #[test]
+    fn current_version_loads_as_saved() {
+        let loaded = load(&[
+            (STORAGE_KEY, fixture!("v2.json")),
+            ("movie-library.watchlist", fixture!("v1_watchlist.json")),
+        ])
+        .unwrap()
+        .unwrap();
+        assert_eq!(loaded.version, 2);
+        assert_eq!(loaded.data.watchlist.entries().len(), 1);
+        assert_eq!(loaded.data.to_json(), fixture!("v2.json"));
+
+        let data = load(&[(STORAGE_KEY, fixture!("v2_without_lists.json"))])
+            .unwrap()
+            .unwrap()
+            .data;
+        assert!(data.lists.lists().is_empty());
+    }
+
+    #[test]
+    fn newer_and_broken_saves_are_refused() {
+        assert_eq!(
+            load(&[(STORAGE_KEY, fixture!("too_new.json"))]),
+            Err(SchemaError::TooNew(99))
+        );
+        assert!(matches!(
+            load(&[(STORAGE_KEY, "{\"watchlist\":[]}")]),
+            Err(SchemaError::Json(_))
+        ));
+    }
+
+    #[test]
+    fn unreadable_version_1_keys_are_set_aside() {
+        let loaded = load(&[
+            (
+                "movie-library.watchlist",
+                fixture!("v1_watchlist_truncated.json"),
+            ),
+            ("movie-library.reviews", fixture!("v1_reviews.json")),
+            ("movie-library.lists", r#"{"version":3,"lists":[]}"#),
+        ])
+        .unwrap()
+        .unwrap();
+        assert_eq!(loaded.version, 1);
+        assert!(loaded.data.watchlist.entries().is_empty());
+        assert!(loaded.data.lists.lists().is_empty());
+        assert_eq!(
+            loaded.data.reviews.get("heat").unwrap().note,
+            "the bank scene"
+        );
+        let keys: Vec<&str> = loaded.unreadable.iter().map(|(key, _)| *key).collect();
+        assert_eq!(keys, ["movie-library.watchlist", "movie-library.lists"]);
+        assert!(matches!(loaded.unreadable[0].1, SchemaError::Json(_)));
+        assert_eq!(
+            loaded.unreadable[1].1,
+            SchemaError::Migration {
+                from: 1,
+                message: "lists has the unknown version 3".to_string()
+            }
+        );
+    }
+}
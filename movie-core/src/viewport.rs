@@ -0,0 +1,74 @@
+//! Which rows of a long list are on screen, so that only those have to be
+//! rendered while spacers stand in for the others.
+
+/// Rows `start..end` of a list, with the height of the rows before and after them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Window {
+    pub start: usize,
+    pub end: usize,
+    pub before: u32,
+    pub after: u32,
+}
+
+/// The rows overlapping `top..top + height` of a list whose rows are
+/// `heights` high, plus `overscan` rows on either side. `top` is negative
+/// while the list starts below the top of the screen.
+pub fn window(heights: &[u32], top: i64, height: u32, overscan: usize) -> Window {
+    let bottom = top + i64::from(height);
+    let (mut first, mut last) = (heights.len(), heights.len());
+    let mut y = 0;
+    for (i, &h) in heights.iter().enumerate() {
+        if first == heights.len() && y + i64::from(h) > top {
+            first = i;
+        }
+        if y >= bottom {
+            last = i;
+            break;
+        }
+        y += i64::from(h);
+    }
+    let start = first.min(last).saturating_sub(overscan);
+    let end = (last + overscan).min(heights.len());
+    Window {
+        start,
+        end,
+        before: heights[..start].iter().sum(),
+        after: heights[end..].iter().sum(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn window_covers_the_screen_and_the_overscan() {
+        let heights = [50; 100];
+        let w = window(&heights, 1000, 500, 0);
+        assert_eq!((w.start, w.end), (20, 30));
+        assert_eq!((w.before, w.after), (1000, 3500));
+
+        // A row cut by the top edge is still on screen.
+        let w = window(&heights, 1010, 500, 2);
+        assert_eq!((w.start, w.end), (18, 33));
+        assert_eq!(w.before + w.after + 15 * 50, 5000);
+    }
+
+    #[test]
+    fn taller_rows_push_the_window_down() {
+        let mut heights = vec![50; 100];
+        heights[0] = 300;
+        let w = window(&heights, 300, 100, 0);
+        assert_eq!((w.start, w.end, w.before), (1, 3, 300));
+    }
+
+    #[test]
+    fn window_is_empty_away_from_the_list() {
+        let heights = [50; 10];
+        let below = window(&heights, -800, 500, 1);
+        assert_eq!((below.start, below.end, below.after), (0, 1, 450));
+        let above = window(&heights, 2000, 500, 1);
+        assert_eq!((above.start, above.end, above.before), (9, 10, 450));
+        assert_eq!(window(&[], 0, 500, 3), Window::default());
+    }
+}
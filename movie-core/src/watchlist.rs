@@ -0,0 +1,61 @@
+//! The watchlist, saved by the app as part of [`crate::userdata::UserData`].
+
+use serde::{Deserialize, Serialize};
+
+/// A movie on a watchlist or a list. The name is kept next to the id so an
+/// entry can still be shown when its movie is no longer in the catalog.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Watchlist {
+    entries: Vec<Entry>,
+}
+
+impl Watchlist {
+    /// Entries in the order they were added.
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    pub fn contains(&self, id: &str) -> bool {
+        self.entries.iter().any(|e| e.id == id)
+    }
+
+    /// Adds the movie if it isn't on the watchlist and removes it otherwise.
+    /// Returns whether it is on the watchlist now.
+    pub fn toggle(&mut self, id: &str, name: &str) -> bool {
+        if self.contains(id) {
+            self.remove(id);
+            return false;
+        }
+        self.entries.push(Entry {
+            id: id.to_string(),
+            name: name.to_string(),
+        });
+        true
+    }
+
+    pub fn remove(&mut self, id: &str) {
+        self.entries.retain(|e| e.id != id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toggling_adds_and_removes() {
+        let mut list = Watchlist::default();
+        assert!(list.toggle("heat", "Heat"));
+        assert!(list.toggle("dune", "Dune"));
+        assert!(!list.toggle("heat", "Heat"));
+        assert!(!list.contains("heat"));
+        assert_eq!(list.entries()[0].name, "Dune");
+    }
+}
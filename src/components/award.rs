@@ -1,113 +1,165 @@
+use crate::components::library;
+use crate::components::watchlist::WatchToggle;
+use crate::Route;
+use gloo_events::EventListener;
+use movie_core::award::{Award, AwardResult, Outcome};
+use movie_core::slug::slug;
+use movie_core::trailer;
+use wasm_bindgen::JsCast;
+use web_sys::KeyboardEvent;
 use yew::prelude::*;
+use yew_router::prelude::*;
 
-#[derive(PartialEq)]
-pub enum Award {
-    Dune,
-    KingRichard,
-    Coda,
-    BeautifulMind,
-    ShapeWater,
-    Parasite,
-    Belfast
+pub enum Msg {
+    Play,
+    Close,
 }
 
-pub struct AwardBinding {
-    title: String,
-    description: String,
-    won: String,
-    trailer: String,
-    img: String,
+pub struct AwardCard {
+    /// Closes the trailer player on Esc; `None` while the player is closed.
+    playing: Option<EventListener>,
 }
 
-impl From<&Award> for AwardBinding {
-    fn from(a: &Award) -> Self {
-        macro_rules! match_p {
-            ( $( $award_key:ident => {
-                    $title:expr, $file: expr, $trailer: expr, $img: expr
-            }, )+
-            ) => { match a {
-                $( Award::$award_key => AwardBinding {
-                    title: $title.to_string(),
-                    description: include_str!(concat!("data/", $file, "_about.txt")).to_string(),
-                    won: include_str!(concat!("data/", $file, "_won.txt")).to_string(),
-                    trailer: $trailer.to_string(),
-                    img: $img.to_string(),
-                }, )+
-            }  };
+impl AwardCard {
+    /// A line per ceremony with a badge per category, wins first.
+    fn view_results(award: &Award) -> Html {
+        let mut ceremonies: Vec<Vec<&AwardResult>> = Vec::new();
+        for result in award.results {
+            match ceremonies.last_mut() {
+                Some(last)
+                    if (last[0].ceremony, last[0].year) == (result.ceremony, result.year) =>
+                {
+                    last.push(result)
+                }
+                _ => ceremonies.push(vec![result]),
+            }
+        }
+        html! {
+            <div class="award-results">
+                { for ceremonies.iter().map(|results| html! {
+                    <div>
+                        <p class="is-size-7 award-event">{results[0].event()}</p>
+                        <div class="tags">
+                            { for results.iter().map(|r| {
+                                let (class, title) = match r.outcome {
+                                    Outcome::Won => ("is-won", "won"),
+                                    Outcome::Nominated => ("is-nominated", "nominated"),
+                                };
+                                html! {
+                                    <span class={classes!("tag", "is-rounded", "award-badge", class)} title={title}>
+                                        {r.category}
+                                    </span>
+                                }
+                            }) }
+                        </div>
+                    </div>
+                }) }
+            </div>
         }
-        match_p!(
-            Dune => { "Dune", "dune", "https://www.youtube.com/watch?v=8g18jFHCLXk",
-            "https://beebom.com/wp-content/uploads/2022/04/Dune.jpg?quality=75&strip=all"
-            },
-            KingRichard => { "King Richard", "king", "https://www.youtube.com/watch?v=BKP_0z52ZAw",
-            "https://beebom.com/wp-content/uploads/2022/04/King-Richard.jpg?quality=75&strip=all"
-            },
-            Coda => { "Coda", "coda", "https://www.youtube.com/watch?v=0pmfrE1YL4I",
-            "https://beebom.com/wp-content/uploads/2022/04/Coda.jpg?quality=75&strip=all"
-            },
-            BeautifulMind => { "A beautiful mind", "mind", "https://www.youtube.com/watch?v=aS_d0Ayjw4o",
-            "https://beebom.com/wp-content/uploads/2022/04/A-Beautiful-Mind.jpg?quality=75&strip=all"
-            },
-            ShapeWater => { "The shape of water", "water", "https://www.youtube.com/watch?v=XFYWazblaUA",
-            "https://beebom.com/wp-content/uploads/2022/04/The-Shape-of-Water.jpg?quality=75&strip=all"
-            },
-            Parasite => { "Parasite", "parasite", "https://www.youtube.com/watch?v=5xH0HfJHsaY",
-            "https://beebom.com/wp-content/uploads/2022/04/Parasite.jpg?quality=75&strip=all"
-            },
-            Belfast => { "Belfast", "belfast", "https://www.youtube.com/watch?v=Ja3PPOnJQ2k",
-            "https://beebom.com/wp-content/uploads/2022/04/Belfast.jpg?quality=75&strip=all"
-            },
-        )
     }
 }
 
-pub struct AwardCard {
-    award: AwardBinding,
-}
-
 #[derive(PartialEq, Properties)]
 pub struct Props {
-    pub award: Award,
+    pub award: &'static Award,
+}
+
+impl AwardCard {
+    fn view_player(ctx: &Context<Self>, id: &str) -> Html {
+        let award = ctx.props().award;
+        let close = ctx.link().callback(|_| Msg::Close);
+        let player = html! {
+            <div class="modal is-active trailer-modal">
+                <div class="modal-background" onclick={close.clone()}></div>
+                <div class="modal-content trailer-player">
+                    <iframe src={trailer::embed_url(id)} title={format!("{} trailer", award.title)}
+                        allow="autoplay; encrypted-media; picture-in-picture" allowfullscreen=true>
+                    </iframe>
+                    <p class="has-text-centered is-size-7">
+                        <a target="_blank" href={award.trailer}>{"open on YouTube"}</a>
+                    </p>
+                </div>
+                <button class="modal-close is-large" aria-label="close" onclick={close}></button>
+            </div>
+        };
+        create_portal(player, gloo_utils::body().into())
+    }
 }
 
 impl Component for AwardCard {
-    type Message = ();
+    type Message = Msg;
     type Properties = Props;
 
-    fn create(ctx: &Context<Self>) -> Self {
-        Self {
-            award: AwardBinding::from(&ctx.props().award),
-        }
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self { playing: None }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        self.playing = match msg {
+            Msg::Play => {
+                let link = ctx.link().clone();
+                let document = gloo_utils::document();
+                Some(EventListener::new(&document, "keydown", move |event| {
+                    let key = event.dyn_ref::<KeyboardEvent>().map(|e| e.key());
+                    if key.as_deref() == Some("Escape") {
+                        link.send_message(Msg::Close);
+                    }
+                }))
+            }
+            Msg::Close => None,
+        };
+        true
     }
 
-    fn view(&self, _ctx: &Context<Self>) -> Html {
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let award = ctx.props().award;
+        let library = library::library();
+        let id = award.movie.and_then(|name| library.id_of(name));
+        let video = trailer::video_id(award.trailer);
         html! {
             <button class="box has-text-centered awardbox">
                 <div class="columns">
                     <div class="column">
                         <div class="subtitle is-4">
-                            {&self.award.title}
+                            {award.title}
                         </div>
                         <p class="subtitle is-italic is-6">
-                            {&self.award.description}
+                            {award.description}
                         </p>
+                        {Self::view_results(award)}
                         <div class="columns has-text-centered is-mobile">
                             <div class="column">
                                 <p class="subtitle is-6">
-                                    {&self.award.won}
+                                    {award.won}
                                 </p>
                             </div>
                             <div class="center">
-                                <a target="_blank" href={format!("{}", &self.award.trailer)}>
-                                    <button class="button is-white is-small is-outlined fixed">
+                                if let Some(id) = video {
+                                    <button class="button is-white is-small is-outlined fixed"
+                                        onclick={ctx.link().callback(|_| Msg::Play)}>
                                         {"watch trailer"}
                                     </button>
-                                </a>
+                                    if self.playing.is_some() {
+                                        {Self::view_player(ctx, id)}
+                                    }
+                                } else {
+                                    <a target="_blank" href={award.trailer}>
+                                        <button class="button is-white is-small is-outlined fixed">
+                                            {"watch trailer"}
+                                        </button>
+                                    </a>
+                                }
+                                <WatchToggle id={id.map_or_else(|| slug(award.title), str::to_string)} name={award.title} label=true />
+                                if let Some(id) = id {
+                                    <Link<Route> to={Route::Movie { id: id.to_string() }}>
+                                        {"in the library"}
+                                    </Link<Route>>
+                                }
                             </div>
                         </div>
                     </div>
                     <div class="column">
-                        <img class="awardedimg" src={format!("{}", &self.award.img)}/>
+                        <img class="awardedimg" src={award.img}/>
                     </div>
                 </div>
             </button>
@@ -0,0 +1,37 @@
+use js_sys::Array;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Blob, BlobPropertyBag, Event, File, HtmlAnchorElement, HtmlInputElement, Url};
+use yew::TargetCast;
+
+/// Offers `contents` to the user as a file download.
+pub fn download(file_name: &str, mime: &str, contents: &str) -> Result<(), JsValue> {
+    let options = BlobPropertyBag::new();
+    options.set_type(mime);
+    let parts = Array::of1(&JsValue::from_str(contents));
+    let blob = Blob::new_with_str_sequence_and_options(&parts, &options)?;
+    let url = Url::create_object_url_with_blob(&blob)?;
+
+    let anchor: HtmlAnchorElement = gloo_utils::document().create_element("a")?.unchecked_into();
+    anchor.set_href(&url);
+    anchor.set_download(file_name);
+    anchor.click();
+    Url::revoke_object_url(&url)
+}
+
+/// The first file picked in the `<input type="file">` that fired `e`.
+pub fn picked_file(e: &Event) -> Option<File> {
+    let input: HtmlInputElement = e.target_unchecked_into();
+    let file = input.files()?.get(0);
+    // Reset so that picking the same file again still fires `change`.
+    input.set_value("");
+    file
+}
+
+pub async fn read_text(file: File) -> Result<String, String> {
+    JsFuture::from(file.text())
+        .await
+        .ok()
+        .and_then(|text| text.as_string())
+        .ok_or_else(|| format!("could not read {}", file.name()))
+}
@@ -0,0 +1,79 @@
+use movie_core::actor;
+use movie_core::catalog;
+use movie_core::graph::CoStarGraph;
+use movie_core::model::Movie;
+use movie_core::slug;
+use std::cell::{OnceCell, RefCell};
+use std::rc::Rc;
+
+/// The catalog shown by the library together with the id of every movie,
+/// shared by the pages that look movies up by id.
+pub struct Library {
+    pub movies: Vec<Rc<Movie>>,
+    pub ids: Vec<String>,
+    /// The [`actor::actor_id`] of every actor of every movie.
+    pub actor_ids: Vec<Rc<[String]>>,
+    graph: OnceCell<CoStarGraph>,
+}
+
+impl Library {
+    fn new(movies: Vec<Rc<Movie>>) -> Self {
+        let ids = slug::movie_ids(&movies);
+        let actor_ids = (actor::actor_ids(&movies).into_iter())
+            .map(Rc::from)
+            .collect();
+        Library {
+            movies,
+            ids,
+            actor_ids,
+            graph: OnceCell::new(),
+        }
+    }
+
+    /// Built the first time a page needs it.
+    pub fn graph(&self) -> &CoStarGraph {
+        self.graph.get_or_init(|| CoStarGraph::new(&self.movies))
+    }
+
+    pub fn position(&self, id: &str) -> Option<usize> {
+        self.ids.iter().position(|i| i == id)
+    }
+
+    /// The id of the movie named exactly `name`, as the award and popular
+    /// manifests name their movies.
+    pub fn id_of(&self, name: &str) -> Option<&str> {
+        let position = self.movies.iter().position(|m| m.name == name)?;
+        Some(&self.ids[position])
+    }
+}
+
+thread_local! {
+    static LIBRARY: RefCell<Option<Rc<Library>>> = const { RefCell::new(None) };
+}
+
+/// The built-in catalog, or the last one imported from a CSV file.
+pub fn library() -> Rc<Library> {
+    LIBRARY.with(|l| {
+        l.borrow_mut()
+            .get_or_insert_with(|| Rc::new(Library::new(movies_vector())))
+            .clone()
+    })
+}
+
+pub fn replace(movies: Vec<Rc<Movie>>) -> Rc<Library> {
+    let library = Rc::new(Library::new(movies));
+    LIBRARY.with(|l| *l.borrow_mut() = Some(library.clone()));
+    library
+}
+
+fn movies_vector() -> Vec<Rc<Movie>> {
+    catalog::parse(catalog::IMDB_DATA)
+        .filter_map(|row| match row {
+            Ok(movie) => Some(Rc::new(movie)),
+            Err(e) => {
+                log::error!("IMDb.txt {}", e);
+                None
+            }
+        })
+        .collect()
+}
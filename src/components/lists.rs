@@ -0,0 +1,92 @@
+use crate::components::storage;
+use movie_core::lists::Lists;
+use movie_core::watchlist::Entry;
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+
+pub fn with<R>(f: impl FnOnce(&Lists) -> R) -> R {
+    storage::with(|d| f(&d.lists))
+}
+
+/// Changes the lists and saves them.
+pub fn update<R>(f: impl FnOnce(&mut Lists) -> R) -> R {
+    storage::update(|d| f(&mut d.lists))
+}
+
+#[derive(PartialEq, Properties)]
+pub struct Props {
+    /// The movie id, see `movie_core::slug`.
+    pub id: String,
+    pub name: String,
+}
+
+pub enum Msg {
+    Add,
+}
+
+/// Picks one of the custom lists to add the movie to.
+pub struct AddToList {
+    list: NodeRef,
+}
+
+impl Component for AddToList {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            list: NodeRef::default(),
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Add => {
+                let picked = self.list.cast::<HtmlSelectElement>().unwrap().value();
+                let Ok(i) = picked.parse::<usize>() else {
+                    return false;
+                };
+                let Props { id, name } = ctx.props();
+                update(|lists| {
+                    if let Some(list) = lists.get_mut(i).filter(|l| !l.contains(id)) {
+                        list.movies.push(Entry {
+                            id: id.clone(),
+                            name: name.clone(),
+                        });
+                    }
+                });
+            }
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let id = &ctx.props().id;
+        let lists: Vec<(String, bool)> = with(|lists| {
+            lists
+                .lists()
+                .iter()
+                .map(|l| (l.name.clone(), l.contains(id)))
+                .collect()
+        });
+        if lists.is_empty() {
+            return html! { <span class="gray4">{"no lists yet, create one in the Lists tab"}</span> };
+        }
+        html! {
+            <div class="inline-form">
+                <div class="select is-small">
+                    <select ref={self.list.clone()}>
+                        { for lists.iter().enumerate().map(|(i, (name, added))| html! {
+                            <option value={i.to_string()} disabled={*added}>
+                                {name}
+                                if *added { {" ✓"} }
+                            </option>
+                        }) }
+                    </select>
+                </div>
+                <button class="button is-white is-small is-outlined"
+                    onclick={ctx.link().callback(|_| Msg::Add)}>{"add to list"}</button>
+            </div>
+        }
+    }
+}
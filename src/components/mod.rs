@@ -1,3 +1,16 @@
+// yew 0.19's html! expands `<Link<Route>>` into statements clippy takes for
+// unnecessary operations, and a component without props into a unit binding.
+#[allow(clippy::unnecessary_operation)]
 pub mod popular;
+#[allow(clippy::unnecessary_operation)]
 pub mod award;
+#[allow(clippy::unnecessary_operation)]
 pub mod movies;
+pub mod file_io;
+pub mod library;
+pub mod lists;
+#[allow(clippy::unnecessary_operation)]
+pub mod similar;
+pub mod reviews;
+pub mod storage;
+pub mod watchlist;
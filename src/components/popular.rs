@@ -1,51 +1,13 @@
+use crate::components::library;
+use crate::components::watchlist::WatchToggle;
+use crate::Route;
 use gloo_timers::future::TimeoutFuture;
+use movie_core::popular::Popular;
+use movie_core::slug::slug;
+use std::fmt;
 use wasm_bindgen_futures::spawn_local;
 use yew::prelude::*;
-
-#[derive(PartialEq)]
-pub enum Popular {
-    MoonageDaydream,
-    TheMenu,
-    ViolentNight,
-    WakandaForever,
-    PokerFace,
-}
-
-struct PopularBinding {
-    title: String,
-    genre: String,
-    runtime: String,
-    description: String,
-    actors: String,
-    gross: String,
-}
-
-impl From<&Popular> for PopularBinding {
-    fn from(p: &Popular) -> Self {
-        macro_rules! match_p {
-            ( $( $popular_key:ident => {
-                    $title:expr, $genre:expr, $runtime: expr, $file:expr
-                }, )+
-            ) => { match p {
-                $( Popular::$popular_key => PopularBinding {
-                    title: $title.to_string(),
-                    genre: $genre.to_string(),
-                    runtime: $runtime.to_string(),
-                    description: include_str!(concat!("data/", $file, "_desc.html")).to_string(),
-                    actors: include_str!(concat!("data/", $file, "_cast.html")).to_string(),
-                    gross: include_str!(concat!("data/", $file, "_gross.html")).to_string(),
-                }, )+
-            }  };
-        }
-        match_p!(
-            MoonageDaydream => { "Moonage Daydream", "Documentary, Music", "134", "moonage" },
-            TheMenu => { "The Menu", "Horor, Mistery, Comedy", "106", "menu" },
-            ViolentNight => { "Violent Night", "Action, Comedy", "101", "violent" },
-            WakandaForever => { "Black Panther: Wakandra Forever", "Action, Adventure", "161", "wakanda" },
-            PokerFace => { "Poker Face", "Thriller", "120", "poker" },
-        )
-    }
-}
+use yew_router::prelude::*;
 
 #[derive(Clone, PartialEq, Debug)]
 pub enum TextVisibility {
@@ -55,14 +17,14 @@ pub enum TextVisibility {
     Gross,
 }
 
-impl TextVisibility {
-    fn to_string(&self) -> String {
-        match self {
-            TextVisibility::None => String::new(),
-            TextVisibility::Description => "description".to_string(),
-            TextVisibility::Actors => "actors".to_string(),
-            TextVisibility::Gross => "gross".to_string(),
-        }
+impl fmt::Display for TextVisibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            TextVisibility::None => "",
+            TextVisibility::Description => "description",
+            TextVisibility::Actors => "actors",
+            TextVisibility::Gross => "gross",
+        })
     }
 }
 
@@ -73,43 +35,32 @@ pub enum Msg {
 
 impl TextVisibility {
     fn show_desc(&self) -> bool {
-        match self {
-            TextVisibility::Description => true,
-            _ => false,
-        }
+        matches!(self, TextVisibility::Description)
     }
     fn show_cast(&self) -> bool {
-        match self {
-            TextVisibility::Actors => true,
-            _ => false,
-        }
+        matches!(self, TextVisibility::Actors)
     }
     fn show_gross(&self) -> bool {
-        match self {
-            TextVisibility::Gross => true,
-            _ => false,
-        }
+        matches!(self, TextVisibility::Gross)
     }
 }
 
 pub struct PopularCard {
-    popular: PopularBinding,
     text: TextVisibility,
     menu: bool,
 }
 
 #[derive(PartialEq, Properties)]
 pub struct Props {
-    pub popular: Popular,
+    pub popular: &'static Popular,
 }
 
 impl Component for PopularCard {
     type Message = Msg;
     type Properties = Props;
 
-    fn create(ctx: &Context<Self>) -> Self {
+    fn create(_ctx: &Context<Self>) -> Self {
         Self {
-            popular: PopularBinding::from(&ctx.props().popular),
             text: TextVisibility::None,
             menu: false,
         }
@@ -139,12 +90,15 @@ impl Component for PopularCard {
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
+        let popular = ctx.props().popular;
+        let library = library::library();
+        let id = popular.movie.and_then(|name| library.id_of(name));
         let desc_div = gloo_utils::document().create_element("div").unwrap();
-        desc_div.set_inner_html(&self.popular.description);
+        desc_div.set_inner_html(popular.description);
         let cast_div = gloo_utils::document().create_element("div").unwrap();
-        cast_div.set_inner_html(&self.popular.actors);
+        cast_div.set_inner_html(popular.actors);
         let gross_div = gloo_utils::document().create_element("div").unwrap();
-        gross_div.set_inner_html(&self.popular.gross);
+        gross_div.set_inner_html(popular.gross);
 
         let menu_class = match self.menu {
             true => "popular-visible",
@@ -193,11 +147,17 @@ impl Component for PopularCard {
                 <div class="columns is-mobile is-centered">
                     <div class="column is-5">
                         <div class="title is-5">
-                            {&self.popular.title}
+                            {popular.title}
                         </div>
                         <div class="subtitle is-6">
-                            {&self.popular.genre}
+                            {popular.genre}
                         </div>
+                        <WatchToggle id={id.map_or_else(|| slug(popular.title), str::to_string)} name={popular.title} label=true />
+                        if let Some(id) = id {
+                            <Link<Route> to={Route::Movie { id: id.to_string() }} classes="populartext">
+                                {"in the library"}
+                            </Link<Route>>
+                        }
                     </div>
                     <div class="column is-2 has-text-centered">
                         <div class="button is-white is-outlined popularbutton"
@@ -208,7 +168,7 @@ impl Component for PopularCard {
                     </div>
                     <div class="column is-5 has-text-right">
                         <div class="title is-5">
-                            {&self.popular.runtime} {" min"}
+                            {popular.runtime} {" min"}
                         </div>
                     </div>
                 </div>
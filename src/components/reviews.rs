@@ -0,0 +1,90 @@
+use crate::components::storage;
+use movie_core::review::{Review, Reviews, Score, NOTE_LIMIT};
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+pub fn with<R>(f: impl FnOnce(&Reviews) -> R) -> R {
+    storage::with(|d| f(&d.reviews))
+}
+
+/// Changes the reviews and saves them.
+pub fn update<R>(f: impl FnOnce(&mut Reviews) -> R) -> R {
+    storage::update(|d| f(&mut d.reviews))
+}
+
+#[derive(PartialEq, Properties)]
+pub struct Props {
+    /// The movie id, see `movie_core::slug`.
+    pub id: String,
+    /// Called once the review was saved or removed.
+    #[prop_or_default]
+    pub on_change: Callback<()>,
+}
+
+pub enum Msg {
+    Save(FocusEvent),
+    Remove,
+}
+
+/// A form to score a movie from 1 to 10 and leave a short note.
+pub struct ReviewEditor {
+    score: NodeRef,
+    note: NodeRef,
+}
+
+impl Component for ReviewEditor {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            score: NodeRef::default(),
+            note: NodeRef::default(),
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let id = &ctx.props().id;
+        match msg {
+            Msg::Save(e) => {
+                e.prevent_default();
+                let score = self.score.cast::<HtmlSelectElement>().unwrap().value();
+                let note = self.note.cast::<HtmlInputElement>().unwrap().value();
+                match score.parse::<Score>() {
+                    Ok(score) => update(|r| r.set(id, Review::new(score, &note))),
+                    Err(_) => update(|r| r.remove(id)),
+                }
+            }
+            Msg::Remove => update(|r| r.remove(id)),
+        }
+        ctx.props().on_change.emit(());
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let review = with(|r| r.get(&ctx.props().id).cloned());
+        let current = review.as_ref().map(|r| r.score);
+        html! {
+            <form class="inline-form" onsubmit={ctx.link().callback(Msg::Save)}>
+                <div class="select is-small">
+                    <select ref={self.score.clone()}>
+                        <option value="" selected={current.is_none()}>{"my score"}</option>
+                        { for Score::ALL.iter().map(|&s| html! {
+                            <option value={s.to_string()} selected={current == Some(s)}>
+                                {format!("{} / 10", s)}
+                            </option>
+                        }) }
+                    </select>
+                </div>
+                <input class="input is-small" type="text" ref={self.note.clone()}
+                    placeholder="a short note" maxlength={NOTE_LIMIT.to_string()}
+                    value={review.map(|r| r.note).unwrap_or_default()}/>
+                <button class="button is-white is-small is-outlined" type="submit">{"save"}</button>
+                if current.is_some() {
+                    <a class="button is-white is-small is-outlined"
+                        onclick={ctx.link().callback(|_| Msg::Remove)}>{"remove"}</a>
+                }
+            </form>
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::components::library;
+use crate::Route;
+use movie_core::similar::{self, Recommendation, Weights};
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+const SIMILAR_MOVIES: usize = 5;
+
+#[derive(PartialEq, Properties)]
+pub struct Props {
+    /// Position of the movie in [`library::library`].
+    pub position: usize,
+}
+
+/// The movies most like the one at `position`, each with the reason it was picked.
+pub struct SimilarMovies {
+    recommendations: Vec<Recommendation>,
+}
+
+impl SimilarMovies {
+    fn recommend(position: usize) -> Vec<Recommendation> {
+        let library = library::library();
+        similar::similar(
+            &library.movies,
+            &library.actor_ids,
+            position,
+            SIMILAR_MOVIES,
+            &Weights::default(),
+        )
+    }
+}
+
+impl Component for SimilarMovies {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            recommendations: Self::recommend(ctx.props().position),
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>) -> bool {
+        self.recommendations = Self::recommend(ctx.props().position);
+        true
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let library = library::library();
+        if self.recommendations.is_empty() {
+            return html! { <p class="gray4">{"Nothing similar in the library."}</p> };
+        }
+        html! {
+            <div class="similar-movies">
+                { for self.recommendations.iter().map(|r| html! {
+                    <div>
+                        <Link<Route> to={Route::Movie { id: library.ids[r.movie].clone() }}>
+                            {&library.movies[r.movie].name}
+                        </Link<Route>>
+                        {format!(" ({} / 10)", library.movies[r.movie].rating)}
+                        <span class="gray4">{format!(" · {}", r.reason())}</span>
+                    </div>
+                }) }
+            </div>
+        }
+    }
+}
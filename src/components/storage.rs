@@ -0,0 +1,155 @@
+use gloo_events::EventListener;
+use movie_core::userdata::{Loaded, SchemaError, UserData, SCHEMA_VERSION, STORAGE_KEY};
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+use wasm_bindgen::JsCast;
+use web_sys::{Storage, StorageEvent};
+use yew::Callback;
+
+struct Store {
+    data: UserData,
+    /// Set when the saved data must not be written over, e.g. because a
+    /// newer version of the app saved it.
+    read_only: bool,
+}
+
+thread_local! {
+    static STORE: RefCell<Option<Store>> = const { RefCell::new(None) };
+    static SUBSCRIBERS: RefCell<Vec<Weak<Callback<()>>>> = const { RefCell::new(Vec::new()) };
+}
+
+fn local_storage() -> Option<Storage> {
+    gloo_utils::window().local_storage().ok().flatten()
+}
+
+/// Reads and, if needed, migrates what earlier visits saved. Data that can't
+/// be read is moved to `<key>.unreadable` rather than overwritten.
+fn load() -> Store {
+    let Some(storage) = local_storage() else {
+        log::error!("local storage is not available, nothing will be saved");
+        return Store {
+            data: UserData::default(),
+            read_only: true,
+        };
+    };
+    let read = |key: &str| storage.get_item(key).ok().flatten();
+    let data = match UserData::load(read) {
+        Ok(None) => UserData::default(),
+        Ok(Some(Loaded {
+            data,
+            version,
+            unreadable,
+        })) => {
+            for (key, e) in unreadable {
+                log::error!("{}: {}, set aside", key, e);
+                keep_unreadable(&storage, key);
+            }
+            if version < SCHEMA_VERSION {
+                log::info!("migrated saved data from version {}", version);
+                save(&data);
+            }
+            data
+        }
+        Err(e @ SchemaError::TooNew(_)) => {
+            log::error!("{}, changes won't be saved", e);
+            return Store {
+                data: UserData::default(),
+                read_only: true,
+            };
+        }
+        Err(e) => {
+            log::error!("{}, starting anew", e);
+            keep_unreadable(&storage, STORAGE_KEY);
+            UserData::default()
+        }
+    };
+    Store {
+        data,
+        read_only: false,
+    }
+}
+
+/// Copies what is saved under `key` to `<key>.unreadable`.
+fn keep_unreadable(storage: &Storage, key: &str) {
+    if let Ok(Some(json)) = storage.get_item(key) {
+        let backup = format!("{}.unreadable", key);
+        if storage.set_item(&backup, &json).is_err() {
+            log::error!("could not keep the unreadable {}", key);
+        }
+    }
+}
+
+fn save(data: &UserData) {
+    let saved = local_storage().map(|s| s.set_item(STORAGE_KEY, &data.to_json()));
+    if !matches!(saved, Some(Ok(()))) {
+        log::error!("could not save {}", STORAGE_KEY);
+    }
+}
+
+fn with_store<R>(f: impl FnOnce(&mut Store) -> R) -> R {
+    STORE.with(|s| {
+        let mut s = s.borrow_mut();
+        let store = match &mut *s {
+            Some(store) => store,
+            None => {
+                listen_to_other_tabs();
+                s.insert(load())
+            }
+        };
+        f(store)
+    })
+}
+
+pub fn with<R>(f: impl FnOnce(&UserData) -> R) -> R {
+    with_store(|s| f(&s.data))
+}
+
+/// Changes the user data and saves it.
+pub fn update<R>(f: impl FnOnce(&mut UserData) -> R) -> R {
+    with_store(|s| {
+        let result = f(&mut s.data);
+        if !s.read_only {
+            save(&s.data);
+        }
+        result
+    })
+}
+
+/// Reloads the user data when another tab saves it. The browser only fires
+/// `storage` in the tabs that didn't make the change.
+fn listen_to_other_tabs() {
+    EventListener::new(&gloo_utils::window(), "storage", |e| {
+        let key = e.dyn_ref::<StorageEvent>().and_then(StorageEvent::key);
+        // No key means the whole storage was cleared.
+        if key.is_none() || key.as_deref() == Some(STORAGE_KEY) {
+            STORE.with(|s| *s.borrow_mut() = Some(load()));
+            notify();
+        }
+    })
+    .forget();
+}
+
+/// Keeps a component informed of changes made in other tabs while it is held.
+pub struct Subscription {
+    _callback: Rc<Callback<()>>,
+}
+
+pub fn subscribe(callback: Callback<()>) -> Subscription {
+    let callback = Rc::new(callback);
+    SUBSCRIBERS.with(|s| s.borrow_mut().push(Rc::downgrade(&callback)));
+    Subscription {
+        _callback: callback,
+    }
+}
+
+fn notify() {
+    let subscribers: Vec<Rc<Callback<()>>> = SUBSCRIBERS.with(|s| {
+        let mut s = s.borrow_mut();
+        s.retain(|w| w.strong_count() > 0);
+        s.iter().filter_map(Weak::upgrade).collect()
+    });
+    // Emitted after the borrow ends, as components created meanwhile subscribe too.
+    for callback in subscribers {
+        callback.emit(());
+    }
+}
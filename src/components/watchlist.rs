@@ -0,0 +1,75 @@
+use crate::components::storage::{self, Subscription};
+use movie_core::watchlist::Watchlist;
+use yew::prelude::*;
+
+pub fn with<R>(f: impl FnOnce(&Watchlist) -> R) -> R {
+    storage::with(|d| f(&d.watchlist))
+}
+
+/// Changes the watchlist and saves it.
+pub fn update<R>(f: impl FnOnce(&mut Watchlist) -> R) -> R {
+    storage::update(|d| f(&mut d.watchlist))
+}
+
+#[derive(PartialEq, Properties)]
+pub struct Props {
+    /// The movie id, see `movie_core::slug`.
+    pub id: String,
+    pub name: String,
+    /// Shows "watchlist" next to the star, for the award and popular cards.
+    #[prop_or_default]
+    pub label: bool,
+}
+
+pub enum Msg {
+    Toggle(MouseEvent),
+    Synced,
+}
+
+/// A star adding the movie to the watchlist or taking it off.
+pub struct WatchToggle {
+    _sync: Subscription,
+}
+
+impl Component for WatchToggle {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            _sync: storage::subscribe(ctx.link().callback(|_| Msg::Synced)),
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Toggle(e) => {
+                e.stop_propagation();
+                let Props { id, name, .. } = ctx.props();
+                update(|w| w.toggle(id, name));
+            }
+            Msg::Synced => {}
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let (star, title) = match with(|w| w.contains(&ctx.props().id)) {
+            true => ("★", "remove from the watchlist"),
+            false => ("☆", "add to the watchlist"),
+        };
+        let class = ctx
+            .props()
+            .label
+            .then_some("button is-white is-small is-outlined");
+        html! {
+            <a class={classes!("watch-toggle", class)} title={title}
+                onclick={ctx.link().callback(Msg::Toggle)}>
+                {star}
+                if ctx.props().label {
+                    {" watchlist"}
+                }
+            </a>
+        }
+    }
+}
@@ -1,3 +1,4 @@
+
 mod pages;
 mod components;
 use yew::prelude::*;
@@ -14,8 +15,20 @@ pub enum Route {
     PopularTab,
     #[at("/awardtab")]
     AwardTab,
+    #[at("/awardstats")]
+    AwardStats,
+    #[at("/watchlisttab")]
+    WatchlistTab,
+    #[at("/liststab")]
+    ListsTab,
     #[at("/abouttab")]
     AboutTab,
+    #[at("/movie/:id")]
+    Movie { id: String },
+    #[at("/actor/:name")]
+    Actor { name: String },
+    #[at("/costars")]
+    CoStars,
 }
 
 pub enum Msg {
@@ -25,6 +38,8 @@ pub struct Model {
     navbar_active: bool,
 }
 
+// The lints tripped by html!, see components/mod.rs.
+#[allow(clippy::let_unit_value, clippy::unnecessary_operation)]
 impl Component for Model {
     type Message = Msg;
     type Properties = ();
@@ -56,6 +71,7 @@ impl Component for Model {
     }
 }
 
+#[allow(clippy::let_unit_value, clippy::unnecessary_operation)]
 impl Model {
     fn view_nav(&self, link: &Scope<Self>) -> Html {
         let Self { navbar_active, .. } = *self;
@@ -87,12 +103,21 @@ impl Model {
                         <Link<Route> classes={classes!("navbar-item")} to={Route::MoviesTab}>
                             { "Library" }
                         </Link<Route>>
+                        <Link<Route> classes={classes!("navbar-item")} to={Route::CoStars}>
+                            { "Co-stars" }
+                        </Link<Route>>
                         <Link<Route> classes={classes!("navbar-item")} to={Route::PopularTab}>
                             { "Popular" }
                         </Link<Route>>
                         <Link<Route> classes={classes!("navbar-item")} to={Route::AwardTab}>
                             { "Awards" }
                         </Link<Route>>
+                        <Link<Route> classes={classes!("navbar-item")} to={Route::WatchlistTab}>
+                            { "Watchlist" }
+                        </Link<Route>>
+                        <Link<Route> classes={classes!("navbar-item")} to={Route::ListsTab}>
+                            { "Lists" }
+                        </Link<Route>>
                         <Link<Route> classes={classes!("navbar-item")} to={Route::AboutTab}>
                             { "About" }
                         </Link<Route>>
@@ -103,13 +128,20 @@ impl Model {
     }
 }
 
+#[allow(clippy::let_unit_value, clippy::unnecessary_operation)]
 fn switch(routes: &Route) -> Html {
     match routes.clone() {
         Route::HomeTab => html! { <pages::hometab::HomeTab /> },
         Route::MoviesTab => html! { <pages::moviestab::MoviesTab /> },
         Route::PopularTab => html! { <pages::populartab::PopularTab /> },
         Route::AwardTab => html! { <pages::awardtab::AwardTab /> },
+        Route::AwardStats => html! { <pages::awardstats::AwardStats /> },
+        Route::WatchlistTab => html! { <pages::watchlisttab::WatchlistTab /> },
+        Route::ListsTab => html! { <pages::liststab::ListsTab /> },
         Route::AboutTab => html! { <pages::abouttab::AboutTab /> },
+        Route::Movie { id } => html! { <pages::moviedetail::MovieDetail id={id} /> },
+        Route::Actor { name } => html! { <pages::actordetail::ActorDetail name={name} /> },
+        Route::CoStars => html! { <pages::costars::CoStars /> },
     }
 }
 
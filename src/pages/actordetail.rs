@@ -0,0 +1,95 @@
+use crate::components::library;
+use crate::pages::costars::{self, Pair};
+use crate::Route;
+use movie_core::actor;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+#[derive(PartialEq, Properties)]
+pub struct Props {
+    /// The [`actor::actor_id`] of the actor.
+    pub name: String,
+}
+
+pub struct ActorDetail;
+impl Component for ActorDetail {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let library = library::library();
+        let film = match actor::filmography(&library.movies, &library.actor_ids, &ctx.props().name) {
+            Some(film) => film,
+            None => {
+                return html! {
+                    <div class="container fade-in has-text-centered" style="max-width: 700px">
+                        <div class="subtitle is-4">{"Actor not found"}</div>
+                        <p>
+                            {format!("No movie in the library stars \"{}\". ", ctx.props().name)}
+                            <Link<Route> to={Route::MoviesTab}>{"Back to the library"}</Link<Route>>
+                        </p>
+                    </div>
+                }
+            }
+        };
+        let genres = film
+            .genres
+            .iter()
+            .map(|(genre, n)| format!("{} {}", genre, n))
+            .collect::<Vec<_>>()
+            .join(" · ");
+        html! {
+            <div class="container fade-in" style="max-width: 1000px">
+                <div class="subtitle is-4 has-text-centered">
+                    {&film.name}
+                </div>
+                <div class="columns is-mobile">
+                    <div class="column is-4 has-text-right gray4">{"movies:"}</div>
+                    <div class="column">{film.movies.len()}</div>
+                </div>
+                <div class="columns is-mobile">
+                    <div class="column is-4 has-text-right gray4">{"average rating:"}</div>
+                    <div class="column">{format!("{} / 10", film.average)}</div>
+                </div>
+                <div class="columns is-mobile">
+                    <div class="column is-4 has-text-right gray4">{"genres:"}</div>
+                    <div class="column">{genres}</div>
+                </div>
+                <div class="columns is-mobile">
+                    <div class="column is-4 has-text-right gray4">{"works most often with:"}</div>
+                    <div class="column">
+                        { costars::view_collaborator_list(library.graph(), &ctx.props().name) }
+                        <Link<Route, Pair> to={Route::CoStars}
+                            query={Some(Pair { from: film.name.clone(), to: String::new() })}>
+                            {"degrees of separation from another actor"}
+                        </Link<Route, Pair>>
+                    </div>
+                </div>
+                <div style="padding: 0px 15px 0px 15px">
+                    { for film.movies.iter().map(|&i| {
+                        let movie = &library.movies[i];
+                        html! {
+                            <div class="columns is-mobile is-gapless is-marginless movie-visible">
+                                <div class="column is-6 movie-name">
+                                    <Link<Route> to={Route::Movie { id: library.ids[i].clone() }}>
+                                        {&movie.name}
+                                    </Link<Route>>
+                                </div>
+                                <div class="column is-3 movie-genre">{movie.genre}</div>
+                                <div class="column is-3 movie-rating">{format!("{} / 10", movie.rating)}</div>
+                            </div>
+                        }
+                    }) }
+                </div>
+                <br/>
+                <div class="has-text-centered">
+                    <Link<Route> to={Route::MoviesTab}>{"Back to the library"}</Link<Route>>
+                </div>
+            </div>
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use crate::Route;
+use movie_core::award::{self, AWARDS};
+use movie_core::chart;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+const LABEL_WIDTH: u32 = 200;
+const BAR_WIDTH: u32 = 340;
+const ROW_HEIGHT: u32 = 26;
+const TIMELINE_WIDTH: u32 = 600;
+
+/// Horizontal bars with their label on the left and their value on the right.
+fn bar_chart(rows: &[(&str, usize)]) -> Html {
+    let values: Vec<usize> = rows.iter().map(|&(_, v)| v).collect();
+    let widths = chart::bar_widths(&values, BAR_WIDTH);
+    let height = ROW_HEIGHT * rows.len() as u32;
+    html! {
+        <svg class="chart" viewBox={format!("0 0 {} {}", LABEL_WIDTH + BAR_WIDTH + 40, height)}>
+            { for rows.iter().zip(widths).enumerate().map(|(i, (&(label, value), width))| {
+                let y = i as u32 * ROW_HEIGHT;
+                html! {
+                    <g>
+                        <text class="chart-label" x={(LABEL_WIDTH - 8).to_string()} y={(y + 17).to_string()}
+                            text-anchor="end">{label}</text>
+                        <rect class="chart-bar" x={LABEL_WIDTH.to_string()} y={(y + 4).to_string()}
+                            width={width.to_string()} height="18" rx="4"/>
+                        <text class="chart-value" x={(LABEL_WIDTH + width + 6).to_string()}
+                            y={(y + 17).to_string()}>{value}</text>
+                    </g>
+                }
+            }) }
+        </svg>
+    }
+}
+
+/// A dot per win on an axis of years, the titles alternating above and below it.
+fn timeline(wins: &[(&str, u16)]) -> Html {
+    let years: Vec<u16> = wins.iter().map(|&(_, year)| year).collect();
+    let xs = chart::year_positions(&years, 80, TIMELINE_WIDTH - 80);
+    html! {
+        <svg class="chart" viewBox={format!("0 0 {} 130", TIMELINE_WIDTH)}>
+            <line class="chart-axis" x1="20" y1="65" x2={(TIMELINE_WIDTH - 20).to_string()} y2="65"/>
+            { for wins.iter().zip(xs).enumerate().map(|(i, (&(title, year), x))| {
+                let (title_y, year_y) = match i % 2 {
+                    0 => (30, 48),
+                    _ => (112, 94),
+                };
+                html! {
+                    <g>
+                        <circle class="chart-bar" cx={x.to_string()} cy="65" r="6"/>
+                        <text class="chart-label" x={x.to_string()} y={title_y.to_string()}
+                            text-anchor="middle">{title}</text>
+                        <text class="chart-value" x={x.to_string()} y={year_y.to_string()}
+                            text-anchor="middle">{year}</text>
+                    </g>
+                }
+            }) }
+        </svg>
+    }
+}
+
+pub struct AwardStats;
+impl Component for AwardStats {
+    type Message = ();
+    type Properties = ();
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        let wins: Vec<(&str, usize)> = award::wins_per_film(AWARDS)
+            .into_iter()
+            .map(|(a, wins)| (a.title, wins))
+            .collect();
+        let categories = award::most_awarded_categories(AWARDS, 10);
+        let best_pictures: Vec<(&str, u16)> = award::best_picture_timeline(AWARDS)
+            .into_iter()
+            .map(|(a, r)| (a.title, r.year))
+            .collect();
+        html! {
+            <div class="container fade-in" style="max-width: 1000px">
+                <div class="subtitle is-4 has-text-centered">
+                    {"Awards in numbers"}
+                </div>
+                <p class="has-text-centered is-size-7">
+                    <Link<Route> to={Route::AwardTab}>{"Back to the awards"}</Link<Route>>
+                </p>
+                <div class="subtitle is-6 has-text-centered gray4">{"Wins per film"}</div>
+                {bar_chart(&wins)}
+                <div class="subtitle is-6 has-text-centered gray4">{"Most awarded categories"}</div>
+                {bar_chart(&categories)}
+                <div class="subtitle is-6 has-text-centered gray4">{"Best Picture winners"}</div>
+                {timeline(&best_pictures)}
+            </div>
+        }
+    }
+}
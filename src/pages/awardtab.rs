@@ -1,28 +1,136 @@
+use crate::components::award::AwardCard;
+use crate::Route;
+use movie_core::award::{self, AwardFilter, AWARDS};
+use serde::{Deserialize, Serialize};
+use web_sys::HtmlSelectElement;
 use yew::prelude::*;
-use crate::components::award::{Award, AwardCard};
+use yew_router::prelude::*;
+use yew_router::scope_ext::HistoryHandle;
+
+/// The filter kept in the query string of /awardtab, e.g. `?ceremony=Academy+Awards`.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Debug, Default)]
+struct AwardParams {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ceremony: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    category: Option<String>,
+}
+
+impl From<AwardParams> for AwardFilter {
+    fn from(params: AwardParams) -> Self {
+        AwardFilter {
+            ceremony: params.ceremony,
+            category: params.category,
+        }
+    }
+}
+
+pub enum Msg {
+    Ceremony(Event),
+    Category(Event),
+    Navigated,
+}
+
+pub struct AwardTab {
+    _history_listener: Option<HistoryHandle>,
+}
+
+impl AwardTab {
+    fn filter(ctx: &Context<Self>) -> AwardFilter {
+        ctx.link()
+            .location()
+            .and_then(|l| l.query::<AwardParams>().ok())
+            .unwrap_or_default()
+            .into()
+    }
+
+    /// The picked option, `None` for the first one that lets everything through.
+    fn picked(e: &Event) -> Option<String> {
+        let value = e.target_unchecked_into::<HtmlSelectElement>().value();
+        (!value.is_empty()).then_some(value)
+    }
+
+    fn select(
+        ctx: &Context<Self>,
+        any: &str,
+        options: &[&'static str],
+        picked: Option<&str>,
+        onchange: fn(Event) -> Msg,
+    ) -> Html {
+        html! {
+            <div class="select is-small">
+                <select onchange={ctx.link().callback(onchange)}>
+                    <option value="" selected={picked.is_none()}>{any}</option>
+                    { for options.iter().map(|&o| html! {
+                        <option value={o} selected={picked == Some(o)}>{o}</option>
+                    }) }
+                </select>
+            </div>
+        }
+    }
+}
 
-pub struct AwardTab;
 impl Component for AwardTab {
-    type Message = ();
+    type Message = Msg;
     type Properties = ();
 
-    fn create(_ctx: &Context<Self>) -> Self {
-        Self
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            _history_listener: ctx
+                .link()
+                .add_history_listener(ctx.link().callback(|_| Msg::Navigated)),
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let mut filter = Self::filter(ctx);
+        match msg {
+            Msg::Ceremony(e) => {
+                filter.ceremony = Self::picked(&e);
+                // A category the new ceremony doesn't have would hide every award.
+                let categories = award::categories(AWARDS, filter.ceremony.as_deref());
+                if let Some(category) = &filter.category {
+                    if !categories.contains(&category.as_str()) {
+                        filter.category = None;
+                    }
+                }
+            }
+            Msg::Category(e) => filter.category = Self::picked(&e),
+            Msg::Navigated => return ctx.link().route::<Route>() == Some(Route::AwardTab),
+        }
+        let params = AwardParams {
+            ceremony: filter.ceremony,
+            category: filter.category,
+        };
+        if let Some(history) = ctx.link().history() {
+            if let Err(e) = history.push_with_query(Route::AwardTab, params) {
+                log::error!("could not update the url: {:?}", e);
+            }
+        }
+        false
     }
 
-    fn view(&self, _ctx: &Context<Self>) -> Html {
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let filter = Self::filter(ctx);
+        let ceremonies = award::ceremonies(AWARDS);
+        let categories = award::categories(AWARDS, filter.ceremony.as_deref());
+        let awards: Vec<_> = AWARDS.iter().filter(|a| filter.matches(a)).collect();
         html! {
             <div class="container fade-in" style="max-width: 1000px">
-               <div class="subtitle is-4 has-text-centered">
+                <div class="subtitle is-4 has-text-centered">
                     {"Movies with awards"}
-               </div>
-               < AwardCard award={Award::Dune} />
-               < AwardCard award={Award::KingRichard} />
-               < AwardCard award={Award::Coda} />
-               < AwardCard award={Award::BeautifulMind} />
-               < AwardCard award={Award::ShapeWater} />
-               < AwardCard award={Award::Parasite} />
-               < AwardCard award={Award::Belfast} />
+                </div>
+                <p class="has-text-centered is-size-7">
+                    <Link<Route> to={Route::AwardStats}>{"Awards in numbers"}</Link<Route>>
+                </p>
+                <div class="inline-form award-filter">
+                    {Self::select(ctx, "every ceremony", &ceremonies, filter.ceremony.as_deref(), Msg::Ceremony)}
+                    {Self::select(ctx, "every category", &categories, filter.category.as_deref(), Msg::Category)}
+                </div>
+                if awards.is_empty() {
+                    <p class="has-text-centered gray4">{"No award matches."}</p>
+                }
+                { for awards.into_iter().map(|award| html! { <AwardCard award={award} /> }) }
             </div>
         }
     }
@@ -0,0 +1,237 @@
+use crate::components::library;
+use crate::Route;
+use movie_core::actor::actor_id;
+use movie_core::graph::CoStarGraph;
+use serde::{Deserialize, Serialize};
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+use yew_router::prelude::*;
+use yew_router::scope_ext::HistoryHandle;
+
+const COLLABORATORS: usize = 5;
+
+/// The two actors as typed, kept in the query string: `/costars?from=Tom+Hardy&to=Toshirô+Mifune`.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Debug, Default)]
+pub struct Pair {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub from: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub to: String,
+}
+
+pub enum Msg {
+    Find(FocusEvent),
+    Navigated,
+}
+
+pub struct CoStars {
+    pair: Pair,
+    from_input: NodeRef,
+    to_input: NodeRef,
+    _history_listener: Option<HistoryHandle>,
+}
+
+/// Every actor of the co-star graph as the `<datalist>` of the actor inputs.
+/// The options are built once and never re-rendered, as diffing thousands of
+/// them on every search of the page would be slow.
+pub struct ActorNames {
+    options: Html,
+}
+
+impl Component for ActorNames {
+    type Message = ();
+    type Properties = ();
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        let library = library::library();
+        let names = library.graph().names();
+        Self {
+            options: names
+                .iter()
+                .map(|name| html! { <option value={name.clone()}/> })
+                .collect(),
+        }
+    }
+
+    fn changed(&mut self, _ctx: &Context<Self>) -> bool {
+        false
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        html! {
+            <datalist id="actor-names">
+                { self.options.clone() }
+            </datalist>
+        }
+    }
+}
+
+impl CoStars {
+    fn pair_in_url(ctx: &Context<Self>) -> Pair {
+        ctx.link()
+            .location()
+            .and_then(|l| l.query::<Pair>().ok())
+            .unwrap_or_default()
+    }
+}
+
+impl Component for CoStars {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            pair: Self::pair_in_url(ctx),
+            from_input: NodeRef::default(),
+            to_input: NodeRef::default(),
+            _history_listener: ctx
+                .link()
+                .add_history_listener(ctx.link().callback(|_| Msg::Navigated)),
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Find(e) => {
+                e.prevent_default();
+                let value = |input: &NodeRef| input.cast::<HtmlInputElement>().unwrap().value();
+                let pair = Pair {
+                    from: value(&self.from_input).trim().to_string(),
+                    to: value(&self.to_input).trim().to_string(),
+                };
+                if let Some(history) = ctx.link().history() {
+                    if let Err(e) = history.push_with_query(Route::CoStars, pair) {
+                        log::error!("could not update the url: {:?}", e);
+                    }
+                }
+                false
+            }
+            Msg::Navigated => {
+                let pair = Self::pair_in_url(ctx);
+                let changed = pair != self.pair;
+                self.pair = pair;
+                changed
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let library = library::library();
+        let graph = library.graph();
+        html! {
+            <div class="container fade-in" style="max-width: 1000px">
+                <div class="subtitle is-4 has-text-centered">
+                    {"Degrees of separation"}
+                </div>
+                <form class="has-text-centered" onsubmit={ctx.link().callback(Msg::Find)}>
+                    <input class="input is-rounded is-white has-text-centered is-small"
+                        style="max-width: 250px" type="text" list="actor-names"
+                        placeholder="First actor..." ref={self.from_input.clone()}
+                        value={self.pair.from.clone()}/>
+                    {" "}
+                    <input class="input is-rounded is-white has-text-centered is-small"
+                        style="max-width: 250px" type="text" list="actor-names"
+                        placeholder="Second actor..." ref={self.to_input.clone()}
+                        value={self.pair.to.clone()}/>
+                    {" "}
+                    <button class="button is-white is-small is-outlined" type="submit">
+                        {"find chain"}
+                    </button>
+                    <ActorNames />
+                </form>
+                <br/>
+                { self.view_chain(graph) }
+                <div class="columns">
+                    { self.view_collaborators(graph, &self.pair.from) }
+                    { self.view_collaborators(graph, &self.pair.to) }
+                </div>
+            </div>
+        }
+    }
+}
+
+impl CoStars {
+    fn view_chain(&self, graph: &CoStarGraph) -> Html {
+        let Pair { from, to } = &self.pair;
+        if from.is_empty() || to.is_empty() {
+            return html! {};
+        }
+        for name in [from, to] {
+            if graph.name(&actor_id(name)).is_none() {
+                return html! {
+                    <p class="has-text-centered has-text-danger">
+                        {format!("No movie in the library stars \"{}\".", name)}
+                    </p>
+                };
+            }
+        }
+        let chain = match graph.chain(&actor_id(from), &actor_id(to)) {
+            Some(chain) => chain,
+            None => {
+                return html! {
+                    <p class="has-text-centered">
+                        {format!("No chain of movies connects {} and {}.", from, to)}
+                    </p>
+                }
+            }
+        };
+        let library = library::library();
+        html! {
+            <div class="has-text-centered">
+                <p class="gray4">
+                    {match chain.movies.len() {
+                        1 => "1 degree of separation".to_string(),
+                        n => format!("{} degrees of separation", n),
+                    }}
+                </p>
+                { for chain.actors.iter().enumerate().map(|(i, actor)| html! {<>
+                    <div class="is-size-5">
+                        <Link<Route> to={Route::Actor { name: actor_id(actor) }}>{actor}</Link<Route>>
+                    </div>
+                    if let Some(&m) = chain.movies.get(i) {
+                        <div class="gray4">
+                            {"↓ "}
+                            <Link<Route> to={Route::Movie { id: library.ids[m].clone() }}>
+                                {&library.movies[m].name}
+                            </Link<Route>>
+                            {" ↓"}
+                        </div>
+                    }
+                </>}) }
+                <br/>
+            </div>
+        }
+    }
+
+    fn view_collaborators(&self, graph: &CoStarGraph, name: &str) -> Html {
+        let id = actor_id(name);
+        match graph.name(&id) {
+            Some(name) => html! {
+                <div class="column">
+                    <p class="has-text-weight-bold">{format!("{} works most often with", name)}</p>
+                    { view_collaborator_list(graph, &id) }
+                </div>
+            },
+            None => html! {},
+        }
+    }
+}
+
+/// The most frequent co-stars of the actor with the id `id`, with the number of shared movies.
+pub fn view_collaborator_list(graph: &CoStarGraph, id: &str) -> Html {
+    html! {
+        <ul>
+            { for graph.collaborators(id, COLLABORATORS).into_iter().map(|c| html! {
+                <li>
+                    <Link<Route> to={Route::Actor { name: actor_id(&c.name) }}>{&c.name}</Link<Route>>
+                    <span class="gray4">
+                        {match c.movies.len() {
+                            1 => " · 1 movie".to_string(),
+                            n => format!(" · {} movies", n),
+                        }}
+                    </span>
+                </li>
+            }) }
+        </ul>
+    }
+}
@@ -0,0 +1,227 @@
+use crate::components::file_io;
+use crate::components::library;
+use crate::components::lists;
+use crate::components::storage::{self, Subscription};
+use crate::Route;
+use movie_core::lists::CustomList;
+use movie_core::slug::slug;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+pub enum Msg {
+    Select(usize),
+    Create(FocusEvent),
+    Rename(FocusEvent),
+    Delete,
+    Move(usize, usize),
+    Remove(usize),
+    Export,
+    Import(Event),
+    Imported(Result<CustomList, String>),
+    Synced,
+}
+
+pub struct ListsTab {
+    selected: usize,
+    new_name: NodeRef,
+    rename: NodeRef,
+    error: Option<String>,
+    _sync: Subscription,
+}
+
+impl ListsTab {
+    fn input(node: &NodeRef) -> String {
+        node.cast::<HtmlInputElement>().unwrap().value()
+    }
+}
+
+impl Component for ListsTab {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            selected: 0,
+            new_name: NodeRef::default(),
+            rename: NodeRef::default(),
+            error: None,
+            _sync: storage::subscribe(ctx.link().callback(|_| Msg::Synced)),
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let selected = self.selected;
+        self.error = None;
+        match msg {
+            Msg::Select(i) => self.selected = i,
+            Msg::Create(e) => {
+                e.prevent_default();
+                let name = Self::input(&self.new_name);
+                match lists::update(|l| l.create(&name)) {
+                    Ok(i) => {
+                        self.selected = i;
+                        self.new_name
+                            .cast::<HtmlInputElement>()
+                            .unwrap()
+                            .set_value("");
+                    }
+                    Err(e) => self.error = Some(e.to_string()),
+                }
+            }
+            Msg::Rename(e) => {
+                e.prevent_default();
+                let name = Self::input(&self.rename);
+                if let Err(e) = lists::update(|l| l.rename(selected, &name)) {
+                    self.error = Some(e.to_string());
+                }
+            }
+            Msg::Delete => {
+                lists::update(|l| l.delete(selected));
+                self.selected = selected.saturating_sub(1);
+            }
+            Msg::Move(from, to) => lists::update(|l| {
+                if let Some(list) = l.get_mut(selected) {
+                    list.move_movie(from, to);
+                }
+            }),
+            Msg::Remove(at) => lists::update(|l| {
+                if let Some(list) = l.get_mut(selected) {
+                    list.remove_movie(at);
+                }
+            }),
+            Msg::Export => {
+                let Some(list) = lists::with(|l| l.get(selected).cloned()) else {
+                    return false;
+                };
+                let file_name = match slug(&list.name) {
+                    s if s.is_empty() => "list.json".to_string(),
+                    s => format!("{}.json", s),
+                };
+                if let Err(e) = file_io::download(&file_name, "application/json", &list.to_json()) {
+                    log::error!("list export failed: {:?}", e);
+                }
+                return false;
+            }
+            Msg::Import(e) => {
+                if let Some(file) = file_io::picked_file(&e) {
+                    let link = ctx.link().clone();
+                    spawn_local(async move {
+                        let list = file_io::read_text(file).await.and_then(|text| {
+                            CustomList::from_json(&text).map_err(|e| e.to_string())
+                        });
+                        link.send_message(Msg::Imported(list));
+                    });
+                }
+                return false;
+            }
+            Msg::Imported(Ok(list)) => match lists::update(|l| l.import(list)) {
+                Ok(i) => self.selected = i,
+                Err(e) => self.error = Some(e.to_string()),
+            },
+            Msg::Imported(Err(e)) => self.error = Some(e),
+            // Another tab may have deleted lists.
+            Msg::Synced => {
+                let len = lists::with(|l| l.lists().len());
+                self.selected = selected.min(len.saturating_sub(1));
+            }
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let (names, list) = lists::with(|l| {
+            let names: Vec<String> = l.lists().iter().map(|l| l.name.clone()).collect();
+            (names, l.get(self.selected).cloned())
+        });
+        html! {
+            <div class="container fade-in" style="max-width: 1000px">
+                <div class="subtitle is-4 has-text-centered">
+                    {"Lists"}
+                </div>
+                <div class="columns">
+                    <div class="column is-4">
+                        <form class="inline-form" onsubmit={ctx.link().callback(Msg::Create)}>
+                            <input class="input is-small" type="text" placeholder="new list..."
+                                ref={self.new_name.clone()}/>
+                            <button class="button is-white is-small is-outlined" type="submit">
+                                {"create"}
+                            </button>
+                        </form>
+                        <label class="csv-import is-size-7">
+                            {"import a list"}
+                            <input type="file" accept=".json,application/json"
+                                onchange={ctx.link().callback(Msg::Import)}/>
+                        </label>
+                        if let Some(e) = &self.error {
+                            <p class="has-text-danger">{e}</p>
+                        }
+                        <ul class="list-names">
+                            { for names.iter().enumerate().map(|(i, name)| html! {
+                                <li>
+                                    <a class={classes!((i == self.selected).then_some("has-text-weight-bold"))}
+                                        onclick={ctx.link().callback(move |_| Msg::Select(i))}>
+                                        {name}
+                                    </a>
+                                </li>
+                            }) }
+                        </ul>
+                    </div>
+                    <div class="column is-8">
+                        if let Some(list) = list {
+                            { self.view_list(ctx, &list) }
+                        } else {
+                            <p class="gray4">{"Create a list, then add movies to it from their page."}</p>
+                        }
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
+
+impl ListsTab {
+    fn view_list(&self, ctx: &Context<Self>, list: &CustomList) -> Html {
+        let library = library::library();
+        let last = list.movies.len().saturating_sub(1);
+        html! {
+            <>
+                <form class="inline-form" onsubmit={ctx.link().callback(Msg::Rename)}>
+                    <input class="input is-small" type="text" ref={self.rename.clone()}
+                        value={list.name.clone()}/>
+                    <button class="button is-white is-small is-outlined" type="submit">{"rename"}</button>
+                    <a class="button is-white is-small is-outlined"
+                        onclick={ctx.link().callback(|_| Msg::Export)}>{"export"}</a>
+                    <a class="button is-white is-small is-outlined"
+                        onclick={ctx.link().callback(|_| Msg::Delete)}>{"delete"}</a>
+                </form>
+                if list.movies.is_empty() {
+                    <p class="gray4">{"No movies yet, add them from their page."}</p>
+                }
+                { for list.movies.iter().enumerate().map(|(i, entry)| html! {
+                    <div class="columns is-mobile is-gapless is-marginless movie-visible">
+                        <div class="column is-1 gray4">{format!("{}.", i + 1)}</div>
+                        <div class="column is-8 movie-name">
+                            if library.position(&entry.id).is_some() {
+                                <Link<Route> to={Route::Movie { id: entry.id.clone() }}>{&entry.name}</Link<Route>>
+                            } else {
+                                {&entry.name}
+                                <span class="gray4">{" · not in the library"}</span>
+                            }
+                        </div>
+                        <div class="column is-3 has-text-right">
+                            if i > 0 {
+                                <a title="move up" onclick={ctx.link().callback(move |_| Msg::Move(i, i - 1))}>{"↑ "}</a>
+                            }
+                            if i < last {
+                                <a title="move down" onclick={ctx.link().callback(move |_| Msg::Move(i, i + 1))}>{"↓ "}</a>
+                            }
+                            <a title="remove from the list" onclick={ctx.link().callback(move |_| Msg::Remove(i))}>{"✕"}</a>
+                        </div>
+                    </div>
+                }) }
+            </>
+        }
+    }
+}
@@ -1,5 +1,22 @@
+// yew 0.19's html! expands `<Link<Route>>` into statements clippy takes for
+// unnecessary operations, and a component without props into a unit binding.
 pub mod abouttab;
 pub mod populartab;
+#[allow(clippy::unnecessary_operation)]
 pub mod hometab;
+#[allow(clippy::unnecessary_operation)]
 pub mod awardtab;
+#[allow(clippy::unnecessary_operation)]
+pub mod awardstats;
+#[allow(clippy::let_unit_value)]
 pub mod moviestab;
+#[allow(clippy::unnecessary_operation)]
+pub mod moviedetail;
+#[allow(clippy::unnecessary_operation)]
+pub mod actordetail;
+#[allow(clippy::let_unit_value, clippy::unnecessary_operation)]
+pub mod costars;
+#[allow(clippy::unnecessary_operation)]
+pub mod watchlisttab;
+#[allow(clippy::unnecessary_operation)]
+pub mod liststab;
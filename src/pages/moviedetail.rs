@@ -0,0 +1,119 @@
+use crate::components::award::AwardCard;
+use crate::components::library;
+use crate::components::lists::AddToList;
+use crate::components::popular::PopularCard;
+use crate::components::reviews::{self, ReviewEditor};
+use crate::components::similar::SimilarMovies;
+use crate::components::storage::{self, Subscription};
+use crate::Route;
+use movie_core::actor;
+use movie_core::award::Award;
+use movie_core::popular::POPULAR;
+use movie_core::review::format_gap;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+#[derive(PartialEq, Properties)]
+pub struct Props {
+    pub id: String,
+}
+
+pub struct MovieDetail {
+    _sync: Subscription,
+}
+
+impl Component for MovieDetail {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            _sync: storage::subscribe(ctx.link().callback(|_| ())),
+        }
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, _msg: Self::Message) -> bool {
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let library = library::library();
+        let (position, movie) = match library.position(&ctx.props().id) {
+            Some(position) => (position, &library.movies[position]),
+            None => {
+                return html! {
+                    <div class="container fade-in has-text-centered" style="max-width: 700px">
+                        <div class="subtitle is-4">{"Movie not found"}</div>
+                        <p>
+                            {format!("There is no movie with the id \"{}\" in the library. ", ctx.props().id)}
+                            <Link<Route> to={Route::MoviesTab}>{"Back to the library"}</Link<Route>>
+                        </p>
+                    </div>
+                }
+            }
+        };
+        let review = reviews::with(|r| r.get(&ctx.props().id).cloned());
+        let award = Award::of_movie(&movie.name);
+        let popular = POPULAR
+            .iter()
+            .filter(|p| p.movie == Some(movie.name.as_str()));
+        html! {
+            <div class="container fade-in" style="max-width: 1000px">
+                <div class="subtitle is-4 has-text-centered">
+                    {&movie.name}
+                </div>
+                <div class="columns is-mobile">
+                    <div class="column is-4 has-text-right gray4">{"genre:"}</div>
+                    <div class="column">{movie.genre}</div>
+                </div>
+                <div class="columns is-mobile">
+                    <div class="column is-4 has-text-right gray4">{"IMDb rating:"}</div>
+                    <div class="column">{format!("{} / 10", movie.rating)}</div>
+                </div>
+                <div class="columns is-mobile">
+                    <div class="column is-4 has-text-right gray4">{"my rating:"}</div>
+                    <div class="column">
+                        if let Some(review) = &review {
+                            <p>
+                                {format!("{} / 10 ({} against IMDb)", review.score, format_gap(review.score.gap(movie.rating)))}
+                            </p>
+                            <p class="is-italic">{&review.note}</p>
+                        }
+                        <ReviewEditor id={ctx.props().id.clone()} on_change={ctx.link().callback(|_| ())} />
+                    </div>
+                </div>
+                <div class="columns is-mobile">
+                    <div class="column is-4 has-text-right gray4">{"cast:"}</div>
+                    <div class="column">
+                        { for movie.actors.iter().map(|actor| html! {
+                            <div>
+                                <Link<Route> to={Route::Actor { name: actor::actor_id(actor) }}>
+                                    {actor}
+                                </Link<Route>>
+                            </div>
+                        }) }
+                    </div>
+                </div>
+                <div class="columns is-mobile">
+                    <div class="column is-4 has-text-right gray4">{"lists:"}</div>
+                    <div class="column">
+                        <AddToList id={ctx.props().id.clone()} name={movie.name.clone()} />
+                    </div>
+                </div>
+                <div class="columns is-mobile">
+                    <div class="column is-4 has-text-right gray4">{"similar movies:"}</div>
+                    <div class="column">
+                        <SimilarMovies position={position} />
+                    </div>
+                </div>
+                if let Some(award) = award {
+                    <AwardCard award={award} />
+                }
+                { for popular.map(|popular| html! { <PopularCard popular={popular} /> }) }
+                <div class="has-text-centered">
+                    <Link<Route> to={Route::MoviesTab}>{"Back to the library"}</Link<Route>>
+                </div>
+            </div>
+        }
+    }
+}
@@ -1,4 +1,5 @@
-use crate::components::popular::{Popular, PopularCard};
+use crate::components::popular::PopularCard;
+use movie_core::popular::POPULAR;
 use yew::prelude::*;
 
 const CINEGRAND_URI: &str = "http://nis.cinegrand-mcf.rs/na-repertoaru-danas";
@@ -21,11 +22,7 @@ impl Component for PopularTab {
                         <a href={CINEGRAND_URI}>{"repertoire"}</a>
                     </div>
                 </div>
-                < PopularCard popular={Popular::MoonageDaydream} />
-                < PopularCard popular={Popular::TheMenu} />
-                < PopularCard popular={Popular::ViolentNight} />
-                < PopularCard popular={Popular::WakandaForever} />
-                < PopularCard popular={Popular::PokerFace} />
+                { for POPULAR.iter().map(|popular| html! { <PopularCard popular={popular} /> }) }
             </div>
         }
     }
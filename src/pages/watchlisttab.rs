@@ -0,0 +1,80 @@
+use crate::components::library;
+use crate::components::storage::{self, Subscription};
+use crate::components::watchlist;
+use crate::Route;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+pub enum Msg {
+    Remove(String),
+    Synced,
+}
+
+pub struct WatchlistTab {
+    _sync: Subscription,
+}
+
+impl Component for WatchlistTab {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            _sync: storage::subscribe(ctx.link().callback(|_| Msg::Synced)),
+        }
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Remove(id) => watchlist::update(|w| w.remove(&id)),
+            Msg::Synced => {}
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let library = library::library();
+        let entries = watchlist::with(|w| w.entries().to_vec());
+        html! {
+            <div class="container fade-in" style="max-width: 1000px">
+                <div class="subtitle is-4 has-text-centered">
+                    {"Watchlist"}
+                </div>
+                if entries.is_empty() {
+                    <p class="has-text-centered gray4">
+                        {"Nothing to watch yet, add movies with the ☆ next to their name."}
+                    </p>
+                }
+                <div style="padding: 0px 15px 0px 15px">
+                    { for entries.into_iter().map(|entry| {
+                        let remove = {
+                            let id = entry.id.clone();
+                            ctx.link().callback(move |_| Msg::Remove(id.clone()))
+                        };
+                        html! {
+                            <div class="columns is-mobile is-gapless is-marginless movie-visible">
+                                if let Some(position) = library.position(&entry.id) {
+                                    <div class="column is-6 movie-name">
+                                        <Link<Route> to={Route::Movie { id: entry.id.clone() }}>
+                                            {&library.movies[position].name}
+                                        </Link<Route>>
+                                    </div>
+                                    <div class="column is-3 movie-genre">{library.movies[position].genre}</div>
+                                    <div class="column is-2 movie-rating">
+                                        {format!("{} / 10", library.movies[position].rating)}
+                                    </div>
+                                } else {
+                                    <div class="column is-6 movie-name">{&entry.name}</div>
+                                    <div class="column is-5 gray4">{"not in the library"}</div>
+                                }
+                                <div class="column is-1 has-text-right">
+                                    <a title="remove from the watchlist" onclick={remove}>{"✕"}</a>
+                                </div>
+                            </div>
+                        }
+                    }) }
+                </div>
+            </div>
+        }
+    }
+}